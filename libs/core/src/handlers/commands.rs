@@ -136,6 +136,7 @@ slu_commands_declaration! {
         #[cfg_attr(all(feature = "gen-binds", not(feature = "salvo")), ts(optional = nullable))]
         umid: Option<String>
     ),
+    GetIconAsIco = get_icon_as_ico(path: PathBuf) -> PathBuf,
     ShowDesktop = show_desktop(),
 
     RequestToUserInputShortcut = request_to_user_input_shortcut(callback_event: String),
@@ -179,6 +180,7 @@ slu_commands_declaration! {
     StateGetWallpapers = state_get_wallpapers() -> Vec<Wallpaper>,
     StateSetCustomIconPack = state_add_icon_to_custom_icon_pack(icon: IconPackEntry),
     StateDeleteCachedIcons = state_delete_cached_icons(),
+    StateRefreshAllIcons = state_refresh_all_icons(),
     RegisterUserCustomAppIcon = register_user_custom_app_icon(icon_base64: String, entry: IconPackEntry),
     DeleteUserCustomAppIcon = delete_user_custom_app_icon(entry: IconPackEntry),
     StateRequestWallpaperAddition = state_request_wallpaper_addition(),
@@ -207,6 +209,23 @@ slu_commands_declaration! {
     GetUserAppWindows = get_user_app_windows() -> Vec<UserAppWindow>,
     GetUserAppWindowsPreviews = get_user_app_windows_previews() -> HashMap<isize, UserAppWindowPreview>,
     GetUserAppWindowsColors = get_user_app_windows_colors() -> HashMap<isize, UserAppWindowColors>,
+    GetGroupPreviews = get_group_previews(exe: PathBuf) -> Vec<(isize, UserAppWindowPreview)>,
+    SetLiveThumbnail = set_live_thumbnail(hwnd: isize, rect: Rect),
+    RemoveLiveThumbnail = remove_live_thumbnail(hwnd: isize),
+    GetJumpList = get_jump_list(umid: String) -> JumpList,
+    ActivateJumpItem = activate_jump_item(umid: String, path: PathBuf),
+    RequestAppAttention = request_app_attention(
+        hwnd: isize,
+        #[cfg_attr(all(feature = "gen-binds", not(feature = "salvo")), ts(optional = nullable))]
+        duration_ms: Option<u32>
+    ),
+    RequestAppAttentionByExe = request_app_attention_by_exe(
+        path: PathBuf,
+        #[cfg_attr(all(feature = "gen-binds", not(feature = "salvo")), ts(optional = nullable))]
+        duration_ms: Option<u32>
+    ),
+    ClearAppAttention = clear_app_attention(hwnd: isize),
+    SendWindowToMonitor = send_window_to_monitor(hwnd: isize, monitor_id: MonitorId),
 
     // Media
     GetMediaDevices = get_media_devices() -> [Vec<MediaDevice>; 2],
@@ -238,7 +257,18 @@ slu_commands_declaration! {
     WegCloseApp = weg_close_app(hwnd: isize),
     WegKillApp = weg_kill_app(hwnd: isize),
     WegToggleWindowState = weg_toggle_window_state(hwnd: isize, was_focused: bool),
+    WegCycleAppWindows = weg_cycle_app_windows(hwnds: Vec<isize>, direction: i32),
+    WegGetNativeTaskbarRect = weg_get_native_taskbar_rect(monitor_id: MonitorId) -> Option<Rect>,
     WegPinItem = weg_pin_item(path: PathBuf),
+    WegOpenFilesWith = weg_open_files_with(program: PathBuf, paths: Vec<PathBuf>),
+    WegPeekDesktop = weg_peek_desktop(monitor_id: MonitorId),
+    WegStopPeekDesktop = weg_stop_peek_desktop(),
+    WegReportInstanceState = weg_report_instance_state(state: WegInstanceState),
+    WegGetRect = weg_get_rect(monitor_id: MonitorId) -> WegInstanceState,
+    WegGetMonitorInfo = weg_get_monitor_info() -> WegMonitorInfo,
+    GetWegDiagnostics = get_weg_diagnostics() -> WegDiagnostics,
+    WegSetInteractive = weg_set_interactive(monitor_id: MonitorId, interactive: bool),
+    WegSetInteractiveRegions = weg_set_interactive_regions(monitor_id: MonitorId, regions: Vec<Rect>),
 
     // Windows Manager
     WmGetRenderTree = wm_get_render_tree() -> TwmGlobalRuntimeTree,
@@ -24,6 +24,7 @@ export enum SeelenCommand {
   GetUserEnvs = "get_user_envs",
   ShowStartMenu = "show_start_menu",
   GetIcon = "get_icon",
+  GetIconAsIco = "get_icon_as_ico",
   ShowDesktop = "show_desktop",
   RequestToUserInputShortcut = "request_to_user_input_shortcut",
   CheckForUpdates = "check_for_updates",
@@ -58,6 +59,7 @@ export enum SeelenCommand {
   StateGetWallpapers = "state_get_wallpapers",
   StateSetCustomIconPack = "state_add_icon_to_custom_icon_pack",
   StateDeleteCachedIcons = "state_delete_cached_icons",
+  StateRefreshAllIcons = "state_refresh_all_icons",
   RegisterUserCustomAppIcon = "register_user_custom_app_icon",
   DeleteUserCustomAppIcon = "delete_user_custom_app_icon",
   StateRequestWallpaperAddition = "state_request_wallpaper_addition",
@@ -80,6 +82,15 @@ export enum SeelenCommand {
   GetUserAppWindows = "get_user_app_windows",
   GetUserAppWindowsPreviews = "get_user_app_windows_previews",
   GetUserAppWindowsColors = "get_user_app_windows_colors",
+  GetGroupPreviews = "get_group_previews",
+  SetLiveThumbnail = "set_live_thumbnail",
+  RemoveLiveThumbnail = "remove_live_thumbnail",
+  GetJumpList = "get_jump_list",
+  ActivateJumpItem = "activate_jump_item",
+  RequestAppAttention = "request_app_attention",
+  RequestAppAttentionByExe = "request_app_attention_by_exe",
+  ClearAppAttention = "clear_app_attention",
+  SendWindowToMonitor = "send_window_to_monitor",
   GetMediaDevices = "get_media_devices",
   GetMediaSessions = "get_media_sessions",
   MediaPrev = "media_prev",
@@ -103,7 +114,18 @@ export enum SeelenCommand {
   WegCloseApp = "weg_close_app",
   WegKillApp = "weg_kill_app",
   WegToggleWindowState = "weg_toggle_window_state",
+  WegCycleAppWindows = "weg_cycle_app_windows",
+  WegGetNativeTaskbarRect = "weg_get_native_taskbar_rect",
   WegPinItem = "weg_pin_item",
+  WegOpenFilesWith = "weg_open_files_with",
+  WegPeekDesktop = "weg_peek_desktop",
+  WegStopPeekDesktop = "weg_stop_peek_desktop",
+  WegReportInstanceState = "weg_report_instance_state",
+  WegGetRect = "weg_get_rect",
+  WegGetMonitorInfo = "weg_get_monitor_info",
+  GetWegDiagnostics = "get_weg_diagnostics",
+  WegSetInteractive = "weg_set_interactive",
+  WegSetInteractiveRegions = "weg_set_interactive_regions",
   WmGetRenderTree = "wm_get_render_tree",
   SetAppWindowsPositions = "set_app_windows_positions",
   RequestFocus = "request_focus",
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, path::PathBuf};
 
 use crate::resource::PluginId;
 use crate::state::*;
@@ -58,6 +58,7 @@ slu_events_declaration! {
     UserChanged(User) as "user-changed",
     UserFolderChanged(FolderChangedArgs) as "user::known-folder-changed",
     UserAppWindowsChanged(Vec<UserAppWindow>) as "user::windows-changed",
+    UserAppWindowsDelta(UserAppWindowsDelta) as "user::windows-delta",
     UserAppWindowsPreviewsChanged(HashMap<isize, UserAppWindowPreview>) as "user::windows-previews-changed",
     UserAppWindowsColorsChanged(HashMap<isize, UserAppWindowColors>) as "user::windows-colors-changed",
 
@@ -113,6 +114,10 @@ slu_events_declaration! {
 
     // SeelenWeg
     WegAddItem(WegItemData) as "weg::add-item",
+    WegRemovePinnedItem(PathBuf) as "weg::remove-pinned-item",
+    WegToggleVisibility(Option<MonitorId>) as "weg::toggle-visibility",
+    WegMonitorInfoChanged(WegMonitorInfo) as "weg::monitor-info",
+    WegInstanceStateChanged(WegInstanceState) as "weg::instance-state",
 
     // Trash Bin
     TrashBinChanged(TrashBinInfo) as "trash-bin::changed",
@@ -10,6 +10,7 @@ export enum SeelenEvent {
   UserChanged = "user-changed",
   UserFolderChanged = "user::known-folder-changed",
   UserAppWindowsChanged = "user::windows-changed",
+  UserAppWindowsDelta = "user::windows-delta",
   UserAppWindowsPreviewsChanged = "user::windows-previews-changed",
   UserAppWindowsColorsChanged = "user::windows-colors-changed",
   MediaSessions = "media-sessions",
@@ -46,6 +47,10 @@ export enum SeelenEvent {
   BluetoothDevicesChanged = "bluetooth-devices-changed",
   StartMenuItemsChanged = "start-menu::items-changed",
   WegAddItem = "weg::add-item",
+  WegRemovePinnedItem = "weg::remove-pinned-item",
+  WegToggleVisibility = "weg::toggle-visibility",
+  WegMonitorInfoChanged = "weg::monitor-info",
+  WegInstanceStateChanged = "weg::instance-state",
   TrashBinChanged = "trash-bin::changed",
   SeelenSessionChanged = "session::changed",
   SeelenBackupStatusChanged = "backup::status-changed",
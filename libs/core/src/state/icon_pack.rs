@@ -200,6 +200,9 @@ pub struct Icon {
     /// Whether the icon is a square or not
     #[serde(skip_serializing_if = "is_false")]
     pub is_aproximately_square: bool,
+    /// Dominant color of the icon as hex string (eg: `#ff0000`), themes can use this to tint app related elements.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dominant_color: Option<String>,
 }
 
 impl Icon {
@@ -14,6 +14,7 @@ function onlyBase(x: string): Icon {
     dark: null,
     mask: null,
     isAproximatelySquare: false,
+    dominantColor: null,
   };
 }
 
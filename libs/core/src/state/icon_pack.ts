@@ -344,6 +344,7 @@ function resolveIcon(parent: string, icon: IIcon): IIcon {
     dark: icon.dark ? `${parent}\\${icon.dark}` : null,
     mask: icon.mask ? `${parent}\\${icon.mask}` : null,
     isAproximatelySquare: icon.isAproximatelySquare,
+    dominantColor: icon.dominantColor,
   };
 }
 
@@ -354,5 +355,6 @@ function resolveAsSrc(icon: IIcon): IIcon {
     dark: icon.dark ? convertFileSrc(icon.dark) : null,
     mask: icon.mask ? convertFileSrc(icon.mask) : null,
     isAproximatelySquare: icon.isAproximatelySquare,
+    dominantColor: icon.dominantColor,
   };
 }
@@ -11,7 +11,7 @@ pub use settings_by_app::*;
 use std::collections::{HashMap, HashSet};
 use std::fs::File;
 use std::io::Write;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -29,6 +29,7 @@ use crate::{
         by_wallpaper::WallpaperInstanceSettings, by_widget::SettingsByWidget,
         shortcuts::SluShortcutsSettings,
     },
+    system_state::Relaunch,
 };
 
 // ============== Fancy Toolbar Settings ==============
@@ -118,6 +119,28 @@ pub enum WegPinnedItemsVisibility {
     WhenPrimary,
 }
 
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(all(feature = "gen-binds", not(feature = "salvo")), derive(ts_rs::TS))]
+#[cfg_attr(all(feature = "gen-binds", not(feature = "salvo")), ts(repr(enum = name)))]
+pub enum WegDockOrder {
+    /// items keep the order set by the user (default)
+    Manual,
+    /// app items are sorted by the last time one of their windows was focused
+    MostRecentlyUsed,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(all(feature = "gen-binds", not(feature = "salvo")), derive(ts_rs::TS))]
+#[cfg_attr(all(feature = "gen-binds", not(feature = "salvo")), ts(repr(enum = name)))]
+pub enum WegItemsFilter {
+    /// pinned items are always shown, unpinned ones while they have windows (default)
+    PinnedAndRunning,
+    /// only items with open windows, pinned or not
+    RunningOnly,
+    /// only pinned items, open windows of unpinned apps are not shown
+    PinnedOnly,
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[cfg_attr(all(feature = "gen-binds", not(feature = "salvo")), derive(ts_rs::TS))]
 #[cfg_attr(all(feature = "gen-binds", not(feature = "salvo")), ts(repr(enum = name)))]
@@ -129,6 +152,20 @@ pub enum HideMode {
     /// auto-hide only if is overlaped by the focused window
     #[serde(alias = "On-Overlap")]
     OnOverlap,
+    /// auto-hide only if the focused window is fullscreen on the same monitor
+    OnFullscreen,
+    /// auto-hide only if the focused window is on another monitor
+    WhenMonitorUnfocused,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(all(feature = "gen-binds", not(feature = "salvo")), derive(ts_rs::TS))]
+#[cfg_attr(all(feature = "gen-binds", not(feature = "salvo")), ts(repr(enum = name)))]
+pub enum WegOverlapTrigger {
+    /// hide if any window on the monitor overlaps the dock while an app is focused (default)
+    Any,
+    /// hide only if the focused window overlaps the dock
+    Focused,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
@@ -151,6 +188,18 @@ pub enum SeelenWegSide {
     Bottom,
 }
 
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(all(feature = "gen-binds", not(feature = "salvo")), derive(ts_rs::TS))]
+#[cfg_attr(all(feature = "gen-binds", not(feature = "salvo")), ts(repr(enum = name)))]
+pub enum WegAlignment {
+    /// at the start of the dock axis (left or top)
+    Start,
+    /// centered along the dock axis (default)
+    Center,
+    /// at the end of the dock axis (right or bottom)
+    End,
+}
+
 #[serde_alias(SnakeCase)]
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[cfg_attr(all(feature = "gen-binds", not(feature = "salvo")), derive(ts_rs::TS))]
@@ -163,19 +212,54 @@ pub struct SeelenWegSettings {
     pub shortcuts: Option<std::collections::HashMap<String, Vec<String>>>,
     /// Dock/Taskbar mode
     pub mode: SeelenWegMode,
+    /// Where the dock is placed along its axis, only used on `MinContent` mode
+    pub alignment: WegAlignment,
     /// When to hide the dock
     pub hide_mode: HideMode,
+    /// Keep the dock over other windows, if false the dock has a normal z-order and topmost windows can cover it
+    pub always_on_top: bool,
+    /// Reserve the dock space on the work area, if false maximized windows will be placed under the dock
+    pub reserve_space: bool,
+    /// Monitors where the native Windows taskbar should be kept visible
+    pub keep_native_taskbar_on: Vec<MonitorId>,
+    /// Keep the native taskbar on every monitor, the dock is placed next to it without reserving space
+    pub coexist_with_taskbar: bool,
+    /// Which windows are considered when `hide_mode` is `OnOverlap`
+    pub overlap_trigger: WegOverlapTrigger,
+    /// Executable file names (case insensitive) of windows that should not trigger `OnOverlap`.
+    pub overlap_blacklist_exe: Vec<String>,
+    /// Windows whose title contains any of these texts (case insensitive) will not trigger `OnOverlap`.
+    pub overlap_blacklist_title: Vec<String>,
     /// Split windows into separated items instead of grouped.
     pub split_windows: bool,
     /// Which temporal items to show on the dock instance (this can be overridden per monitor)
     pub temporal_items_visibility: WegTemporalItemsVisibility,
     /// Determines is the pinned item should be shown or not (this can be overridden per monitor).
     pub pinned_items_visibility: WegPinnedItemsVisibility,
+    /// Which app items are shown, filtered by their pinned and running state.
+    pub items_filter: WegItemsFilter,
+    /// How app items are sorted inside each group, reordering by drag is disabled on `MostRecentlyUsed`.
+    pub dock_order: WegDockOrder,
+    /// Windows with these class names (case insensitive) are not shown on the dock.
+    /// Entries can use `*` as wildcard, e.g. `SplashWindow*`.
+    pub class_blacklist: Vec<String>,
+    /// Windows with these titles (case insensitive) are not shown on the dock.
+    /// Entries can use `*` as wildcard, e.g. `Loading*`.
+    pub title_blacklist: Vec<String>,
+    /// Only show the windows of the active workspace of each monitor.
+    pub only_current_desktop: bool,
+    /// Executable file names (case insensitive) whose windows, and the windows of any process
+    /// started by them, are not shown on the dock and do not trigger `OnOverlap`.
+    pub ignore_process_tree: Vec<String>,
+    /// Coalesce the app window changes of a frame into a single `user::windows-delta` event,
+    /// instead of emitting the whole list on every change.
+    pub batched_updates: bool,
     /// Dock position
     pub position: SeelenWegSide,
     /// enable or disable the instance counter visibility on weg instance
     pub show_instance_counter: bool,
-    /// enable or disable the window title visibility for opened apps
+    /// enable or disable the window title visibility for opened apps,
+    /// on vertical docks the dock gets wider to fit the titles
     pub show_window_title: bool,
     /// item size in px
     pub size: u32,
@@ -185,16 +269,32 @@ pub struct SeelenWegSettings {
     pub margin: u32,
     /// Dock/Taskbar padding in px
     pub padding: u32,
+    /// Dock/Taskbar thickness in px (cross axis size without margin), independent of the item size.
+    /// 0 means the thickness is given by the item size and padding. It can't be smaller than the items.
+    pub thickness: u32,
     /// space between items in px
     pub space_between_items: u32,
+    /// Max items per line (row or column depending on the position) of each items group,
+    /// the dock grows with new lines when exceeded. 0 means a single line. Only used on `MinContent` mode.
+    pub max_items_per_line: u32,
     /// delay to show the toolbar on Mouse Hover in milliseconds
     pub delay_to_show: u32,
     /// delay to hide the toolbar on Mouse Leave in milliseconds
     pub delay_to_hide: u32,
+    /// size in px of the strip along the screen edge that reveals the hidden dock when the cursor enters it
+    pub reveal_zone_size: u32,
+    /// duration of the slide animation on hide/show in milliseconds, 0 to disable it
+    pub animation_duration: u32,
     /// show end task button on context menu (needs developer mode enabled)
     pub show_end_task: bool,
     /// Action to perform when middle-clicking a dock item
     pub middle_click_action: WegMiddleClickAction,
+    /// User defined icons by executable path (case insensitive), used instead of the extracted ones.
+    /// Values can be images or modules with an optional icon index, e.g. `C:\app\icons.dll,3`.
+    pub icon_overrides: HashMap<String, PathBuf>,
+    /// Custom way to launch apps, by executable path (case insensitive) or app user model id.
+    /// Used instead of the detected path/relaunch information of the dock item.
+    pub launch_overrides: HashMap<String, Relaunch>,
 }
 
 impl Default for SeelenWegSettings {
@@ -203,22 +303,43 @@ impl Default for SeelenWegSettings {
             enabled: true,
             shortcuts: None,
             mode: SeelenWegMode::MinContent,
+            alignment: WegAlignment::Center,
             hide_mode: HideMode::OnOverlap,
+            always_on_top: true,
+            reserve_space: true,
+            keep_native_taskbar_on: Vec::new(),
+            coexist_with_taskbar: false,
+            overlap_trigger: WegOverlapTrigger::Any,
+            overlap_blacklist_exe: Vec::new(),
+            overlap_blacklist_title: Vec::new(),
             position: SeelenWegSide::Bottom,
             show_instance_counter: true,
             show_window_title: false,
             temporal_items_visibility: WegTemporalItemsVisibility::All,
             pinned_items_visibility: WegPinnedItemsVisibility::Always,
+            items_filter: WegItemsFilter::PinnedAndRunning,
+            dock_order: WegDockOrder::Manual,
+            class_blacklist: Vec::new(),
+            title_blacklist: Vec::new(),
+            only_current_desktop: false,
+            ignore_process_tree: Vec::new(),
+            batched_updates: false,
             size: 40,
             zoom_size: 70,
             margin: 8,
             padding: 8,
+            thickness: 0,
             space_between_items: 8,
+            max_items_per_line: 0,
             delay_to_show: 100,
             delay_to_hide: 800,
+            reveal_zone_size: 1,
+            animation_duration: 200,
             show_end_task: false,
             split_windows: false,
             middle_click_action: WegMiddleClickAction::OpenNewInstance,
+            icon_overrides: HashMap::new(),
+            launch_overrides: HashMap::new(),
         }
     }
 }
@@ -22,6 +22,9 @@ pub enum AppExtraFlag {
     /// Pin this app in all the virtual desktops in the wm.
     #[serde(alias = "pinned", alias = "vd-pinned")]
     VdPinned,
+    /// Pin this app to the dock the first time one of its windows appears.
+    #[serde(alias = "weg-pinned")]
+    WegPinned,
     #[serde(other)]
     Unknown,
 }
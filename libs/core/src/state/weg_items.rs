@@ -3,8 +3,9 @@ use std::{collections::HashSet, path::PathBuf};
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    rect::Rect,
     resource::PluginId,
-    system_state::{Relaunch, RelaunchArguments},
+    system_state::{MonitorId, Relaunch, RelaunchArguments},
 };
 
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
@@ -37,6 +38,10 @@ pub enum WegItem {
     Separator {
         id: uuid::Uuid,
     },
+    /// empty slot of the size of an item, used to visually group items
+    Spacer {
+        id: uuid::Uuid,
+    },
     Media {
         id: uuid::Uuid,
     },
@@ -67,6 +72,7 @@ impl WegItem {
             WegItem::DeprecatedOldPinned(data) => &data.id,
             WegItem::AppOrFile(data) => &data.id,
             WegItem::Separator { id } => id,
+            WegItem::Spacer { id } => id,
             WegItem::Media { id } => id,
             WegItem::DeprecatedStartMenu { id } => id,
             WegItem::DeprecatedShowDesktop { id } => id,
@@ -80,6 +86,7 @@ impl WegItem {
             WegItem::DeprecatedOldPinned(data) => data.id = identifier,
             WegItem::AppOrFile(data) => data.id = identifier,
             WegItem::Separator { id } => *id = identifier,
+            WegItem::Spacer { id } => *id = identifier,
             WegItem::Media { id } => *id = identifier,
             WegItem::DeprecatedStartMenu { id } => *id = identifier,
             WegItem::DeprecatedShowDesktop { id } => *id = identifier,
@@ -252,3 +259,53 @@ pub struct OldWegItemData {
     #[serde(skip_deserializing)]
     pub pin_disabled: bool,
 }
+
+/// Last known placement of a dock instance, as reported by the dock itself.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(all(feature = "gen-binds", not(feature = "salvo")), derive(ts_rs::TS))]
+#[serde(rename_all = "camelCase")]
+pub struct WegInstanceState {
+    /// monitor of the dock, filled by the background from the reporting webview
+    pub monitor_id: MonitorId,
+    /// physical rect of the dock (without the invisible area of the webview), in screen coordinates
+    pub rect: Rect,
+    /// amount of rows (or columns) of items on multi-line layouts
+    pub lines: u32,
+    /// the dock is currently hidden (auto hide or toggled by the user)
+    pub is_hidden: bool,
+    /// a window is overlapping the dock
+    pub is_overlapped: bool,
+}
+
+/// Counters of the dock related operations since the app started, to diagnose performance
+/// reports without a debugger.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(all(feature = "gen-binds", not(feature = "salvo")), derive(ts_rs::TS))]
+#[serde(rename_all = "camelCase")]
+pub struct WegDiagnostics {
+    /// counters are only collected on builds with the `diagnostics` feature
+    pub enabled: bool,
+    pub windows_added: u32,
+    pub windows_removed: u32,
+    pub windows_updated: u32,
+    /// icon requests resolved by an already extracted icon
+    pub icon_cache_hits: u32,
+    /// icon requests that needed an extraction
+    pub icon_cache_misses: u32,
+    /// times a dock changed from overlapped to not overlapped or vice versa
+    pub overlap_toggles: u32,
+}
+
+/// Monitor metadata of a dock instance, needed to render the dock at the right scale.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(all(feature = "gen-binds", not(feature = "salvo")), derive(ts_rs::TS))]
+#[serde(rename_all = "camelCase")]
+pub struct WegMonitorInfo {
+    pub monitor_id: MonitorId,
+    /// win32 device name, e.g. `\\.\DISPLAY1`
+    pub device_name: String,
+    /// dpi ratio of the monitor, text scale included
+    pub scale_factor: f64,
+    /// physical rect of the monitor excluding the space reserved by app bars
+    pub work_area: Rect,
+}
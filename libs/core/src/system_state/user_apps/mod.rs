@@ -29,6 +29,26 @@ pub struct FocusedApp {
     pub is_fullscreened: bool,
     /// this is the rect of the window, without the shadow.
     pub rect: Option<Rect>,
+    /// the interactable window (the one shown on the dock) that represents the focused window.
+    pub app_window: Option<FocusedAppWindow>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(all(feature = "gen-binds", not(feature = "salvo")), derive(ts_rs::TS))]
+#[serde(rename_all = "camelCase")]
+pub struct FocusedAppWindow {
+    pub hwnd: isize,
+    pub matched_by: FocusedAppMatch,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(all(feature = "gen-binds", not(feature = "salvo")), derive(ts_rs::TS))]
+#[cfg_attr(all(feature = "gen-binds", not(feature = "salvo")), ts(repr(enum = name)))]
+pub enum FocusedAppMatch {
+    /// the focused window is itself an interactable window
+    Hwnd,
+    /// the focused window is owned by an interactable window, ex: dialogs or popups
+    Owner,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -38,10 +58,16 @@ pub struct UserAppWindow {
     pub hwnd: isize,
     pub monitor: MonitorId,
     pub title: String,
+    /// title without the trailing app name (`Document - Word` -> `Document`), for compact labels
+    pub short_title: String,
+    /// window class name
+    pub class: String,
     pub app_name: String,
     pub is_zoomed: bool,
     pub is_iconic: bool,
     pub is_fullscreen: bool,
+    /// the window is "always on top" (`WS_EX_TOPMOST`)
+    pub is_topmost: bool,
     /// this can be from the window property store, or inherited from the process
     pub umid: Option<String>,
     /// if the window is a frame, this information will be mapped to the process creator
@@ -56,6 +82,43 @@ pub struct UserAppWindow {
     /// 0 means it has never been focused since tracking started.
     /// clients that want z-order-like sorting should sort descending by this field.
     pub last_foreground_at: i64,
+    /// custom icon key to be used instead of the generic missing icon
+    /// when the icon of this app can not be extracted.
+    pub fallback_icon: Option<String>,
+    /// the app requested the user attention (`FlashWindowEx`), cleared when the window is focused.
+    pub requires_attention: bool,
+    /// the app config asks this app to be pinned to the dock (`AppExtraFlag::WegPinned`)
+    pub pinned_by_config: bool,
+    /// the window belongs to the active workspace of its monitor (or is pinned to all of them).
+    pub on_current_desktop: bool,
+}
+
+/// Changes on the app windows coalesced during a frame, emitted when batched updates are enabled.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(all(feature = "gen-binds", not(feature = "salvo")), derive(ts_rs::TS))]
+#[serde(rename_all = "camelCase")]
+pub struct UserAppWindowsDelta {
+    pub added: Vec<UserAppWindow>,
+    /// handles of the removed windows
+    pub removed: Vec<isize>,
+    pub updated: Vec<UserAppWindow>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(all(feature = "gen-binds", not(feature = "salvo")), derive(ts_rs::TS))]
+#[serde(rename_all = "camelCase")]
+pub struct JumpListItem {
+    pub label: String,
+    pub path: PathBuf,
+}
+
+/// Documents lists of an app, as shown on the native taskbar jump lists.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(all(feature = "gen-binds", not(feature = "salvo")), derive(ts_rs::TS))]
+#[serde(rename_all = "camelCase")]
+pub struct JumpList {
+    pub recent: Vec<JumpListItem>,
+    pub frequent: Vec<JumpListItem>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -63,6 +126,8 @@ pub struct UserAppWindow {
 pub struct ProcessInformation {
     pub id: u32,
     pub path: Option<PathBuf>,
+    /// exe names of the parent processes chain, nearest first
+    pub ancestors: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
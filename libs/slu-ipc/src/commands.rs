@@ -270,6 +270,27 @@ pub enum WegCommand {
         /// Which index should be started on weg.
         index: usize,
     },
+    /// Hides or shows the dock, all docks are toggled if no monitor is specified.
+    ToggleVisibility {
+        /// Id of the monitor where the dock is placed.
+        #[arg(long)]
+        monitor: Option<String>,
+    },
+    /// Pins an application to the dock.
+    Pin {
+        /// Full path to the executable, or the exe name of an open application.
+        path: String,
+    },
+    /// Unpins an application from the dock.
+    Unpin {
+        /// Full path to the executable, or the exe name of a pinned or open application.
+        path: String,
+    },
+    /// Focuses the most recently active window of the application, restoring it if minimized.
+    Focus {
+        /// Exe name or full path of the executable.
+        exe: String,
+    },
 }
 
 // ===== WindowManager =====
@@ -98,7 +98,12 @@ pub enum SvcAction {
     SetForeground(isize),
     StartShortcutRegistration,
     StopShortcutRegistration,
-    HideNativeTaskbar,
+    HideNativeTaskbar {
+        /// monitor handles (HMONITOR) whose taskbar should be kept visible
+        keep_on_monitors: Vec<isize>,
+        /// re-enumerate the taskbars instead of using the cached ones (ex: a new taskbar was created)
+        refresh: bool,
+    },
     RestoreNativeTaskbar,
 }
 
@@ -3,6 +3,8 @@ export * from "./PersistentRune.svelte";
 export * from "./bluetoothIcons";
 export * from "./i18n";
 export * from "./signals.svelte";
+export * from "./userAppWindows";
+export * from "./userAppWindows.svelte";
 
 /** Get relative time string from a date (e.g., "2 hours ago", "3 days ago") */
 export function relativeTimeFromNow(date: Date): string {
@@ -0,0 +1,21 @@
+import { invoke, SeelenCommand, SeelenEvent, subscribe } from "@seelen-ui/lib";
+import { lazyRune } from "./LazyRune.svelte";
+import { applyUserAppWindowsDelta } from "./userAppWindows";
+
+/**
+ * List of the interactable app windows, kept updated from both the whole list and the batched
+ * deltas emitted when `batchedUpdates` is enabled on the dock settings.
+ */
+export function userAppWindowsRune() {
+  const windows = lazyRune(() => invoke(SeelenCommand.GetUserAppWindows));
+  subscribe(SeelenEvent.UserAppWindowsChanged, windows.setByPayload);
+  subscribe(SeelenEvent.UserAppWindowsDelta, async ({ payload }) => {
+    if (windows.isInitialized()) {
+      windows.value = applyUserAppWindowsDelta(windows.value, payload);
+      return;
+    }
+    // the initial fetch could be older than this delta
+    windows.value = await invoke(SeelenCommand.GetUserAppWindows);
+  });
+  return windows;
+}
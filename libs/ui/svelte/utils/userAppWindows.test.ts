@@ -0,0 +1,37 @@
+import type { UserAppWindow } from "@seelen-ui/lib/types";
+import { assertEquals } from "jsr:@std/assert@^1.0.19";
+import { applyUserAppWindowsDelta } from "./userAppWindows.ts";
+
+function win(hwnd: number, title = ""): UserAppWindow {
+  return { hwnd, title } as UserAppWindow;
+}
+
+Deno.test("removed windows are dropped", () => {
+  const result = applyUserAppWindowsDelta([win(1), win(2), win(3)], { added: [], removed: [2], updated: [] });
+  assertEquals(result, [win(1), win(3)]);
+});
+
+Deno.test("updated windows keep their position", () => {
+  const result = applyUserAppWindowsDelta([win(1), win(2), win(3)], {
+    added: [],
+    removed: [],
+    updated: [win(2, "new title")],
+  });
+  assertEquals(result, [win(1), win(2, "new title"), win(3)]);
+});
+
+Deno.test("added windows go to the end", () => {
+  const result = applyUserAppWindowsDelta([win(1)], { added: [win(2), win(3)], removed: [], updated: [] });
+  assertEquals(result, [win(1), win(2), win(3)]);
+});
+
+Deno.test("added windows already on the list are not duplicated", () => {
+  const result = applyUserAppWindowsDelta([win(1), win(2)], { added: [win(1, "again")], removed: [], updated: [] });
+  assertEquals(result, [win(1, "again"), win(2)]);
+});
+
+Deno.test("the input list is not mutated", () => {
+  const windows = [win(1), win(2)];
+  applyUserAppWindowsDelta(windows, { added: [win(3)], removed: [1], updated: [] });
+  assertEquals(windows, [win(1), win(2)]);
+});
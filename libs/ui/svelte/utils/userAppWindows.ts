@@ -0,0 +1,24 @@
+import type { UserAppWindow, UserAppWindowsDelta } from "@seelen-ui/lib/types";
+
+/**
+ * Applies a batched change of the app windows to the list, keeping the order of the backend:
+ * updated windows stay in place and added ones go to the end.
+ */
+export function applyUserAppWindowsDelta(windows: UserAppWindow[], delta: UserAppWindowsDelta): UserAppWindow[] {
+  const removed = new Set(delta.removed);
+  const updated = new Map(delta.updated.map((w) => [w.hwnd, w]));
+
+  const result = windows
+    .filter((w) => !removed.has(w.hwnd))
+    .map((w) => updated.get(w.hwnd) ?? w);
+
+  for (const window of delta.added) {
+    const index = result.findIndex((w) => w.hwnd === window.hwnd);
+    if (index === -1) {
+      result.push(window);
+    } else {
+      result[index] = window;
+    }
+  }
+  return result;
+}
@@ -0,0 +1,69 @@
+import { type Rect, SeelenWegSide, type SeelenWegSettings } from "@seelen-ui/lib/types";
+import { assertEquals } from "jsr:@std/assert@^1.0.19";
+import { getDockLogicalSize, getDockRects, getDockThickness } from "./wegGeometry.ts";
+
+// side by side monitors, the secondary one is on the right with 150% scaling
+const PRIMARY: Rect = { left: 0, top: 0, right: 1920, bottom: 1080 };
+const SECONDARY: Rect = { left: 1920, top: 0, right: 4800, bottom: 1620 };
+
+// 40px of items + 4px of margin on each side
+const LOGICAL_SIZE = 48;
+
+Deno.test("dock thickness is not scaled on 100% monitors", () => {
+  const { hitboxRect } = getDockRects(PRIMARY, SeelenWegSide.Bottom, LOGICAL_SIZE, 1, false);
+  assertEquals(hitboxRect, { left: 0, top: 1032, right: 1920, bottom: 1080 });
+});
+
+Deno.test("dock thickness is scaled on 150% monitors", () => {
+  const { hitboxRect } = getDockRects(SECONDARY, SeelenWegSide.Bottom, LOGICAL_SIZE, 1.5, false);
+  assertEquals(hitboxRect, { left: 1920, top: 1548, right: 4800, bottom: 1620 });
+});
+
+Deno.test("rects stay on the physical coordinates of the secondary monitor", () => {
+  const { hitboxRect, webviewRect } = getDockRects(SECONDARY, SeelenWegSide.Left, LOGICAL_SIZE, 1.5, false);
+  assertEquals(hitboxRect, { left: 1920, top: 0, right: 1992, bottom: 1620 });
+  assertEquals(webviewRect, { left: 1920, top: 0, right: 3360, bottom: 1620 });
+});
+
+Deno.test("webview covers half of the monitor to leave room for popups", () => {
+  const { webviewRect } = getDockRects(SECONDARY, SeelenWegSide.Bottom, LOGICAL_SIZE, 1.5, false);
+  assertEquals(webviewRect, { left: 1920, top: 810, right: 4800, bottom: 1620 });
+});
+
+Deno.test("webview matches the hitbox on touch devices", () => {
+  for (const side of Object.values(SeelenWegSide)) {
+    const { hitboxRect, webviewRect } = getDockRects(SECONDARY, side, LOGICAL_SIZE, 1.5, true);
+    assertEquals(webviewRect, hitboxRect);
+  }
+});
+
+const SIZES = {
+  position: SeelenWegSide.Bottom,
+  size: 40,
+  padding: 8,
+  margin: 4,
+  spaceBetweenItems: 8,
+  thickness: 0,
+  showWindowTitle: false,
+} as unknown as SeelenWegSettings;
+
+Deno.test("dock thickness defaults to the items and padding", () => {
+  assertEquals(getDockThickness(SIZES, 1), 56);
+  assertEquals(getDockLogicalSize(SIZES, 1), 64);
+});
+
+Deno.test("multi-line docks add a line of items and its gap", () => {
+  assertEquals(getDockThickness(SIZES, 2), 40 * 2 + 8 + 8 * 2);
+});
+
+Deno.test("explicit thickness is used unless the items don't fit", () => {
+  assertEquals(getDockThickness({ ...SIZES, thickness: 70 }, 1), 70);
+  assertEquals(getDockThickness({ ...SIZES, thickness: 70 }, 2), 88);
+});
+
+Deno.test("vertical docks showing titles fit the labels", () => {
+  const vertical = { ...SIZES, position: SeelenWegSide.Left, showWindowTitle: true };
+  assertEquals(getDockThickness(vertical, 1), 40 * 3 + 8 * 2 + 8 * 2);
+  // horizontal docks show the titles below the items
+  assertEquals(getDockThickness({ ...SIZES, showWindowTitle: true }, 1), 56);
+});
@@ -0,0 +1,80 @@
+import { type Rect, SeelenWegSide, type SeelenWegSettings } from "@seelen-ui/lib/types";
+
+export interface DockRects {
+  /** area of the dock itself, used for the app bar reservation */
+  hitboxRect: Rect;
+  /** area of the webview, bigger than the dock to leave room for popups and zoomed items */
+  webviewRect: Rect;
+}
+
+type DockSizeSettings = Pick<
+  SeelenWegSettings,
+  "position" | "size" | "padding" | "margin" | "spaceBetweenItems" | "thickness" | "showWindowTitle"
+>;
+
+function isHorizontalSide(side: SeelenWegSide): boolean {
+  return side === SeelenWegSide.Top || side === SeelenWegSide.Bottom;
+}
+
+/** Cross axis size of the items, vertical docks showing window titles are wide to fit the labels. */
+export function getDockItemCrossSize(settings: DockSizeSettings): number {
+  if (settings.showWindowTitle && !isHorizontalSide(settings.position)) {
+    return settings.size * 3 + settings.spaceBetweenItems * 2;
+  }
+  return settings.size;
+}
+
+/** Dock cross axis size without margin in css pixels, defaults to the items and padding. */
+export function getDockThickness(settings: DockSizeSettings, lines: number): number {
+  const itemsSize = getDockItemCrossSize(settings) * lines + settings.spaceBetweenItems * (lines - 1);
+  if (settings.thickness) {
+    return Math.max(settings.thickness, itemsSize);
+  }
+  return itemsSize + settings.padding * 2;
+}
+
+/** Dock cross axis size including margins in css pixels, as expected by `getDockRects`. */
+export function getDockLogicalSize(settings: DockSizeSettings, lines: number): number {
+  return getDockThickness(settings, lines) + settings.margin * 2;
+}
+
+/**
+ * Computes the dock rects on a monitor. All the rects are in physical pixels, `logicalSize`
+ * is the dock thickness (including margins) in css pixels and is scaled by the monitor factor.
+ */
+export function getDockRects(
+  workArea: Rect,
+  side: SeelenWegSide,
+  logicalSize: number,
+  scaleFactor: number,
+  isTouch: boolean,
+): DockRects {
+  const hitboxRect = { ...workArea };
+  const webviewRect = { ...workArea };
+  const size = Math.round(logicalSize * scaleFactor);
+
+  switch (side) {
+    case SeelenWegSide.Left:
+      hitboxRect.right = hitboxRect.left + size;
+      webviewRect.right = isTouch
+        ? hitboxRect.right
+        : workArea.right - Math.round((workArea.right - workArea.left) / 2);
+      break;
+    case SeelenWegSide.Right:
+      hitboxRect.left = hitboxRect.right - size;
+      webviewRect.left = isTouch ? hitboxRect.left : workArea.left + Math.round((workArea.right - workArea.left) / 2);
+      break;
+    case SeelenWegSide.Top:
+      hitboxRect.bottom = hitboxRect.top + size;
+      webviewRect.bottom = isTouch
+        ? hitboxRect.bottom
+        : workArea.top + Math.round((workArea.bottom - workArea.top) / 2);
+      break;
+    case SeelenWegSide.Bottom:
+      hitboxRect.top = hitboxRect.bottom - size;
+      webviewRect.top = isTouch ? hitboxRect.top : workArea.bottom - Math.round((workArea.bottom - workArea.top) / 2);
+      break;
+  }
+
+  return { hitboxRect, webviewRect };
+}
@@ -17,6 +17,7 @@ use crate::{
     state::application::{initialize_user_resources_watcher, AppSettings, FULL_STATE},
     utils::{
         discord::{start_discord_rpc, update_discord_rpc},
+        icon_extractor::sync_user_icon_overrides,
         CRONOMETER,
     },
     widgets::{
@@ -25,7 +26,7 @@ use crate::{
     },
     windows_api::{
         event_window::{create_background_window, IS_INTERACTIVE_SESSION},
-        Com,
+        AppBarData, Com,
     },
     APP_HANDLE,
 };
@@ -89,6 +90,9 @@ impl SeelenUI {
             SeelenWeg::hide_native_taskbar();
         }
 
+        // before the widgets register their own bars
+        AppBarData::remove_orphaned_bars();
+
         WIDGET_MANAGER.reconcile()?;
         CRONOMETER.record("reconcile");
 
@@ -137,6 +141,8 @@ impl SeelenUI {
             SeelenWeg::restore_native_taskbar()?;
         }
 
+        sync_user_icon_overrides(&state.settings.by_widget.weg.icon_overrides);
+
         // Re-emit user and session so streaming mode redaction takes effect immediately.
         reemit_user();
         reemit_session();
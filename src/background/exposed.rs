@@ -16,7 +16,10 @@ use crate::{
     utils::{
         self,
         constants::SEELEN_COMMON,
-        icon_extractor::{request_icon_extraction_from_file, request_icon_extraction_from_umid},
+        icon_extractor::{
+            extract_icon_multi, request_icon_extraction_from_file,
+            request_icon_extraction_from_umid,
+        },
         pwsh::PwshScript,
     },
     widgets::{
@@ -123,6 +126,12 @@ fn get_icon(path: Option<PathBuf>, umid: Option<String>) -> Result<()> {
     Ok(())
 }
 
+// the dock uses the webp from the icon pack, this is for tooling that needs the full icon
+#[tauri::command(async)]
+fn get_icon_as_ico(path: PathBuf) -> Result<PathBuf> {
+    extract_icon_multi(&path)
+}
+
 #[tauri::command(async)]
 async fn check_for_updates() -> Result<bool> {
     Ok(utils::updater::check_for_updates().await?.is_some())
@@ -24,6 +24,7 @@ use crate::{
     app::emit_to_webviews,
     error::{Result, ResultLogExt},
     event_manager,
+    modules::apps::application::UserAppsManager,
     state::application::FULL_STATE,
     utils::spawn_named_thread,
     widgets::weg::SeelenWeg,
@@ -145,7 +146,7 @@ impl HookManager {
             if shoup_update_focused && origin.is_focused() {
                 emit_to_webviews(
                     SeelenEvent::GlobalFocusChanged,
-                    origin.as_focused_app_information(),
+                    UserAppsManager::instance().focused_app_information(&origin),
                 );
             }
         }
@@ -182,6 +183,7 @@ pub fn register_win_hook() -> Result<()> {
             if let Ok(pos) = Mouse::get_cursor_pos() {
                 if last_pos != pos {
                     emit_to_webviews(SeelenEvent::GlobalMouseMove, &[pos.x, pos.y]);
+                    SeelenWeg::on_cursor_moved(&pos);
                     last_pos = pos;
                 }
             }
@@ -0,0 +1,77 @@
+use std::path::{Path, PathBuf};
+
+use seelen_core::system_state::{JumpList, JumpListItem};
+use windows::{
+    core::HSTRING,
+    Win32::UI::Shell::{
+        ApplicationDocumentLists, Common::IObjectArray, IApplicationDocumentLists, IShellItem,
+        ADLT_FREQUENT, ADLT_RECENT, APPDOCLISTTYPE, SIGDN, SIGDN_FILESYSPATH, SIGDN_NORMALDISPLAY,
+    },
+};
+
+use crate::{
+    error::Result,
+    windows_api::{Com, WindowsApi},
+};
+
+/// same amount of items shown by the native taskbar by default
+const MAX_ITEMS_PER_LIST: u32 = 10;
+
+/// Reads the recent and frequent documents that the shell tracks for the app user model id.
+/// Only file items are returned, tasks and custom categories are not exposed by the shell.
+pub fn get_jump_list(umid: &str) -> Result<JumpList> {
+    Com::run_with_context(|| {
+        let lists: IApplicationDocumentLists = Com::create_instance(&ApplicationDocumentLists)?;
+        unsafe { lists.SetAppID(&HSTRING::from(umid))? };
+        Ok(JumpList {
+            recent: read_list(&lists, ADLT_RECENT),
+            frequent: read_list(&lists, ADLT_FREQUENT),
+        })
+    })
+}
+
+/// Opens an item of the jump list of the app, fails if the item is not on it.
+pub fn activate_jump_item(umid: &str, path: &Path) -> Result<()> {
+    let list = get_jump_list(umid)?;
+    let is_listed = list
+        .recent
+        .iter()
+        .chain(list.frequent.iter())
+        .any(|item| item.path == path);
+    if !is_listed {
+        return Err("The item is not part of the app jump list".into());
+    }
+    WindowsApi::execute(path.to_string_lossy().to_string(), None, None, false)
+}
+
+fn read_list(lists: &IApplicationDocumentLists, list_type: APPDOCLISTTYPE) -> Vec<JumpListItem> {
+    let Ok(array) = (unsafe { lists.GetList::<IObjectArray>(list_type, MAX_ITEMS_PER_LIST) })
+    else {
+        return Vec::new();
+    };
+    let count = unsafe { array.GetCount() }.unwrap_or(0);
+
+    let mut items = Vec::new();
+    for index in 0..count {
+        // entries that are not shell items are links to commands, those are skipped
+        let Ok(item) = (unsafe { array.GetAt::<IShellItem>(index) }) else {
+            continue;
+        };
+        let Some(path) = display_name(&item, SIGDN_FILESYSPATH) else {
+            continue;
+        };
+        let label = display_name(&item, SIGDN_NORMALDISPLAY).unwrap_or_else(|| path.clone());
+        items.push(JumpListItem {
+            label,
+            path: PathBuf::from(path),
+        });
+    }
+    items
+}
+
+fn display_name(item: &IShellItem, kind: SIGDN) -> Option<String> {
+    let name = unsafe { item.GetDisplayName(kind) }.ok()?;
+    let value = unsafe { name.to_string() }.ok();
+    Com::task_mem_free(name.0 as _);
+    value
+}
@@ -0,0 +1,127 @@
+use std::sync::{LazyLock, Once};
+
+use seelen_core::rect::Rect;
+use windows::Win32::{
+    Foundation::{HWND, RECT},
+    Graphics::Dwm::{
+        DwmQueryThumbnailSourceSize, DwmRegisterThumbnail, DwmUnregisterThumbnail,
+        DwmUpdateThumbnailProperties, DWM_THUMBNAIL_PROPERTIES, DWM_TNP_OPACITY,
+        DWM_TNP_RECTDESTINATION, DWM_TNP_SOURCECLIENTAREAONLY, DWM_TNP_VISIBLE,
+    },
+};
+
+use crate::{
+    error::{Result, ResultLogExt},
+    hook::HookManager,
+    modules::apps::application::{UserAppWinEvent, UserAppsManager},
+    utils::lock_free::SyncHashMap,
+    windows_api::window::event::WinEvent,
+};
+
+/// Live thumbnails composed by DWM, keyed by (destination, source) window addresses.
+/// Unlike the GDI captures, these also work for hardware-accelerated and occluded windows.
+static LIVE_THUMBNAILS: LazyLock<SyncHashMap<(isize, isize), isize>> =
+    LazyLock::new(SyncHashMap::new);
+
+fn init() {
+    static INIT: Once = Once::new();
+    INIT.call_once(|| {
+        UserAppsManager::subscribe(|e| {
+            if let UserAppWinEvent::Removed(addr) = e {
+                unregister_where(|_, source| source == addr);
+            }
+        });
+        // the popup can be hidden in many ways (focus loss, dock hidden, etc.) without the
+        // webview noticing it, so the thumbnails drawn over it are released here.
+        HookManager::subscribe(|(event, window)| {
+            if matches!(event, WinEvent::ObjectHide | WinEvent::ObjectDestroy) {
+                let addr = window.address();
+                unregister_where(|destination, _| destination == addr);
+            }
+        });
+    });
+}
+
+fn unregister_where(predicate: impl Fn(isize, isize) -> bool) {
+    LIVE_THUMBNAILS.retain(|((destination, source), thumbnail)| {
+        if predicate(*destination, *source) {
+            let _ = unsafe { DwmUnregisterThumbnail(*thumbnail) };
+            return false;
+        }
+        true
+    });
+}
+
+/// Renders a live thumbnail of `source` fitted and centered on `rect` (physical pixels, relative
+/// to the client area of `destination`). Calling it again for the same pair only moves it.
+pub fn set_live_thumbnail(destination: HWND, source: HWND, rect: &Rect) -> Result<()> {
+    init();
+
+    let key = (destination.0 as isize, source.0 as isize);
+    // only the hovered preview is shown live, a missed hover end must not leave others drawn
+    unregister_where(|d, s| d == key.0 && s != key.1);
+
+    let thumbnail = match LIVE_THUMBNAILS.get(&key, |t| *t) {
+        Some(thumbnail) => thumbnail,
+        None => {
+            let thumbnail = unsafe { DwmRegisterThumbnail(destination, source)? };
+            LIVE_THUMBNAILS.upsert(key, thumbnail);
+            thumbnail
+        }
+    };
+
+    let properties = DWM_THUMBNAIL_PROPERTIES {
+        dwFlags: DWM_TNP_RECTDESTINATION
+            | DWM_TNP_VISIBLE
+            | DWM_TNP_OPACITY
+            | DWM_TNP_SOURCECLIENTAREAONLY,
+        rcDestination: fit_to_rect(thumbnail, rect),
+        opacity: 255,
+        fVisible: true.into(),
+        fSourceClientAreaOnly: false.into(),
+        ..Default::default()
+    };
+
+    if let Err(err) = unsafe { DwmUpdateThumbnailProperties(thumbnail, &properties) } {
+        // the source window could have been destroyed, so the thumbnail is no longer valid
+        LIVE_THUMBNAILS.remove(&key);
+        unsafe { DwmUnregisterThumbnail(thumbnail).log_error() };
+        return Err(err.into());
+    }
+    Ok(())
+}
+
+pub fn remove_live_thumbnail(destination: HWND, source: HWND) -> Result<()> {
+    let key = (destination.0 as isize, source.0 as isize);
+    if let Some(thumbnail) = LIVE_THUMBNAILS.remove(&key) {
+        unsafe { DwmUnregisterThumbnail(thumbnail)? };
+    }
+    Ok(())
+}
+
+/// Keeps the aspect ratio of the source window, so the preview is not stretched.
+fn fit_to_rect(thumbnail: isize, rect: &Rect) -> RECT {
+    let width = rect.right - rect.left;
+    let height = rect.bottom - rect.top;
+    let (mut fit_w, mut fit_h) = (width, height);
+
+    if let Ok(size) = unsafe { DwmQueryThumbnailSourceSize(thumbnail) } {
+        if size.cx > 0 && size.cy > 0 && width > 0 && height > 0 {
+            let scale = f64::min(
+                width as f64 / size.cx as f64,
+                height as f64 / size.cy as f64,
+            );
+            fit_w = (size.cx as f64 * scale).round() as i32;
+            fit_h = (size.cy as f64 * scale).round() as i32;
+        }
+    }
+
+    let left = rect.left + (width - fit_w) / 2;
+    let top = rect.top + (height - fit_h) / 2;
+    RECT {
+        left,
+        top,
+        right: left + fit_w,
+        bottom: top + fit_h,
+    }
+}
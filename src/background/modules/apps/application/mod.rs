@@ -1,3 +1,5 @@
+pub mod jump_list;
+pub mod live_thumbnails;
 pub mod msix;
 pub mod msix_manifest;
 pub mod previews;
@@ -7,14 +9,24 @@ pub use windows::*;
 
 use std::sync::LazyLock;
 
-use seelen_core::system_state::UserAppWindow;
+use seelen_core::system_state::{FocusedApp, FocusedAppMatch, FocusedAppWindow, UserAppWindow};
 
-use crate::{event_manager, utils::lock_free::SyncVec, windows_api::window::Window};
+use crate::{
+    event_manager,
+    utils::{
+        icon_extractor::{request_icon_extraction_from_file, request_icon_extraction_from_umid},
+        lock_free::SyncVec,
+    },
+    windows_api::{process::Process, window::Window},
+};
 
 pub static USER_APPS_MANAGER: LazyLock<UserAppsManager> = LazyLock::new(UserAppsManager::init);
 
 pub struct UserAppsManager {
     pub interactable_windows: SyncVec<UserAppWindow>,
+    /// Generation of the last attention request per window, timed clears only apply to
+    /// the request that scheduled them.
+    attention_generations: AttentionGenerations,
 }
 
 #[allow(dead_code)]
@@ -31,6 +43,7 @@ impl UserAppsManager {
     fn init() -> Self {
         Self {
             interactable_windows: SyncVec::from(Self::init_listing_app_windows()),
+            attention_generations: AttentionGenerations::default(),
         }
     }
 
@@ -43,6 +56,37 @@ impl UserAppsManager {
         self.interactable_windows.any(|w| w.hwnd == hwnd)
     }
 
+    /// Focus information of `window`, including the interactable window that represents it on
+    /// the dock. Owned windows (dialogs, popups) are matched by walking up the owner chain.
+    pub fn focused_app_information(&self, window: &Window) -> FocusedApp {
+        let mut info = window.as_focused_app_information();
+        info.app_window = self.resolve_app_window(window);
+        info
+    }
+
+    fn resolve_app_window(&self, window: &Window) -> Option<FocusedAppWindow> {
+        if self.contains_win(window) {
+            return Some(FocusedAppWindow {
+                hwnd: window.address(),
+                matched_by: FocusedAppMatch::Hwnd,
+            });
+        }
+
+        let mut current = window.owner();
+        // owner chains are short, the limit is only a guard against cycles
+        for _ in 0..8 {
+            let owner = current?;
+            if self.contains_win(&owner) {
+                return Some(FocusedAppWindow {
+                    hwnd: owner.address(),
+                    matched_by: FocusedAppMatch::Owner,
+                });
+            }
+            current = owner.owner();
+        }
+        None
+    }
+
     /// Atomically inserts `window` if it isn't already tracked. Returns `true` if it was
     /// inserted. Using a single locked check-and-insert avoids the race between the WinEvent
     /// dispatcher thread and the `InteractableWindowsRevalidator` thread both observing the
@@ -50,7 +94,8 @@ impl UserAppsManager {
     fn add_win(&self, window: &Window) -> bool {
         let hwnd = window.address();
         let is_focused = window.is_focused();
-        self.interactable_windows.get_or_insert_with(
+        let mut added = None;
+        let inserted = self.interactable_windows.get_or_insert_with(
             |w| w.hwnd == hwnd,
             || {
                 log::trace!("Adding: {window}");
@@ -58,14 +103,62 @@ impl UserAppsManager {
                 if is_focused {
                     serialized.last_foreground_at = windows::now_millis();
                 }
+                added = Some(serialized.clone());
                 serialized
             },
-        )
+        );
+
+        // windows of the same app share the icon, so it is only requested for the first one
+        if let Some(app) = added {
+            let has_sibling = self.interactable_windows.any(|w| {
+                w.hwnd != app.hwnd && w.umid == app.umid && w.process.path == app.process.path
+            });
+            if !has_sibling {
+                request_app_icon(&app);
+            }
+        }
+        inserted
     }
 
     fn remove_win(&self, window: &Window) {
         log::trace!("Removing: {window}");
         let hwnd = window.address();
-        self.interactable_windows.retain(|w| w.hwnd != hwnd);
+        let mut process_id = None;
+        self.interactable_windows.retain(|w| {
+            if w.hwnd == hwnd {
+                process_id = Some(w.process.id);
+            }
+            w.hwnd != hwnd
+        });
+        self.attention_generations.forget(hwnd);
+        if let Some(process_id) = process_id {
+            self.forget_process_if_untracked(process_id);
+        }
+    }
+
+    /// Drops the cached data of the process once none of its windows is tracked.
+    fn forget_process_if_untracked(&self, process_id: u32) {
+        if !self
+            .interactable_windows
+            .any(|w| w.process.id == process_id)
+        {
+            Process::from_id(process_id).forget_ancestors();
+        }
+    }
+}
+
+/// Queues the icon extraction of the app window. This doesn't block the caller, the window is
+/// tracked with the missing/fallback icon and the real one is shown once the icon pack is updated.
+pub(crate) fn request_app_icon(app: &UserAppWindow) {
+    if let Some(umid) = &app.umid {
+        request_icon_extraction_from_umid(&umid.clone().into());
+    }
+    match app.relaunch.as_ref().and_then(|r| r.icon.as_ref()) {
+        Some(icon) => request_icon_extraction_from_file(icon),
+        None => {
+            if let Some(path) = &app.process.path {
+                request_icon_extraction_from_file(path);
+            }
+        }
     }
 }
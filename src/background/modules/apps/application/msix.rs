@@ -5,13 +5,18 @@ use std::{
 
 use windows::ApplicationModel::{AppInfo, Package};
 
-use crate::{error::Result, modules::apps::application::msix_manifest::PackageManifest};
+use crate::{
+    error::Result, modules::apps::application::msix_manifest::PackageManifest,
+    windows_api::MonitorEnumerator,
+};
 
 static UWP_LIGHTUNPLATED_POSTFIX: &str = "_altform-lightunplated";
 static UWP_UNPLATED_POSTFIX: &str = "_altform-unplated";
 static UWP_TARGET_SIZE_POSTFIXES: &[&str] = &[
     ".targetsize-256",
     ".targetsize-96",
+    ".targetsize-80",
+    ".targetsize-72",
     ".targetsize-64",
     ".targetsize-48",
     ".targetsize-32",
@@ -160,14 +165,48 @@ fn get_icon_via_display_info(app_info: &AppInfo, app_umid: &str) -> Option<(Path
     Some((path.clone(), path))
 }
 
+/// The same icon is shared by all the monitors, so we take the highest scale to avoid
+/// upscaled (blurry) icons in any of them.
+fn max_monitor_scale_factor() -> f64 {
+    MonitorEnumerator::enumerate_win32()
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|monitor| monitor.scale_factor().ok())
+        .fold(1.0, f64::max)
+}
+
+/// Returns the scale postfixes ordered by how well they fit the scale factor: first the equal
+/// or closest bigger one (downscaling keeps the icon crisp), then the closest smaller ones.
+/// Some packages only ship `scale-200` so the fallback order still matters.
+fn uwp_scale_postfixes_for(scale_factor: f64) -> Vec<&'static str> {
+    let target = (scale_factor * 100.0).round() as u32;
+    let mut postfixes: Vec<(u32, &'static str)> = UWP_SCALE_POSTFIXES
+        .iter()
+        .filter_map(|postfix| {
+            let scale = postfix.strip_prefix(".scale-")?.parse().ok()?;
+            Some((scale, *postfix))
+        })
+        .collect();
+
+    postfixes.sort_by_key(|(scale, _)| {
+        if *scale >= target {
+            (0, scale - target)
+        } else {
+            (1, target - scale)
+        }
+    });
+    postfixes.into_iter().map(|(_, postfix)| postfix).collect()
+}
+
 // returns light and dark icons
 pub fn get_hightest_quality_posible_for_uwp_image(icon_path: &Path) -> Option<(PathBuf, PathBuf)> {
     let filename = icon_path.file_stem()?.to_str()?;
     let extension = icon_path.extension()?.to_str()?;
 
+    let scale_postfixes = uwp_scale_postfixes_for(max_monitor_scale_factor());
     let size_postfixes = (*UWP_TARGET_SIZE_POSTFIXES)
         .iter()
-        .chain((*UWP_SCALE_POSTFIXES).iter());
+        .chain(scale_postfixes.iter());
 
     for size_postfix in size_postfixes {
         let light_icon = icon_path.with_file_name(format!(
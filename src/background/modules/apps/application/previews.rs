@@ -230,6 +230,23 @@ impl WinPreviewManager {
         map
     }
 
+    /// Cached previews of the given windows in the same order, windows not captured yet
+    /// are skipped and queued to be captured.
+    pub fn get_previews_of(&self, windows: &[isize]) -> Vec<(isize, UserAppWindowPreview)> {
+        let mut previews = Vec::new();
+        for addr in windows {
+            let preview = self
+                .previews
+                .get(addr, |wrap| wrap.preview.clone())
+                .flatten();
+            match preview {
+                Some(preview) => previews.push((*addr, preview)),
+                None => self.enqueue_capture(*addr),
+            }
+        }
+        previews
+    }
+
     pub fn get_colors(&self) -> HashMap<isize, UserAppWindowColors> {
         let mut map = HashMap::new();
         self.previews.for_each(|(k, v)| {
@@ -1,19 +1,21 @@
 use std::{
-    sync::atomic::Ordering,
+    sync::{atomic::Ordering, LazyLock},
     time::{SystemTime, UNIX_EPOCH},
 };
 
 use seelen_core::{state::AppExtraFlag, system_state::UserAppWindow};
 use windows::Win32::UI::WindowsAndMessaging::{
-    WS_CHILD, WS_EX_APPWINDOW, WS_EX_NOACTIVATE, WS_EX_TOOLWINDOW, WS_MINIMIZEBOX,
+    HSHELL_FLASH, WS_CHILD, WS_EX_APPWINDOW, WS_EX_NOACTIVATE, WS_EX_TOOLWINDOW, WS_MINIMIZEBOX,
 };
 
 use crate::{
     hook::HookManager,
     modules::apps::application::{UserAppWinEvent, UserAppsManager, USER_APPS_MANAGER},
-    utils::spawn_named_thread,
+    utils::{lock_free::SyncHashMap, short_title, spawn_named_thread},
     windows_api::{
-        event_window::IS_INTERACTIVE_SESSION,
+        event_window::{
+            subscribe_to_background_window, IS_INTERACTIVE_SESSION, WM_SHELLHOOKMESSAGE,
+        },
         window::{event::WinEvent, Window},
         WindowEnumerator, WindowsApi,
     },
@@ -45,6 +47,13 @@ impl UserAppsManager {
         }
 
         HookManager::subscribe(|(event, window)| Self::on_win_event(event, window));
+        subscribe_to_background_window(|msg, w_param, l_param| {
+            if msg == WM_SHELLHOOKMESSAGE.load(Ordering::Relaxed) && w_param as u32 == HSHELL_FLASH
+            {
+                Self::on_attention_requested(l_param);
+            }
+            Ok(())
+        });
 
         spawn_named_thread("InteractableWindowsRevalidator", || loop {
             std::thread::sleep(std::time::Duration::from_millis(5000));
@@ -54,15 +63,21 @@ impl UserAppsManager {
                 continue;
             }
 
+            let mut removed_processes = Vec::new();
             Self::instance().interactable_windows.retain(|w| {
                 let window = Window::from(w.hwnd);
                 if window.is_interactable_and_not_hidden() {
                     true
                 } else {
                     Self::send(UserAppWinEvent::Removed(window.address()));
+                    USER_APPS_MANAGER.attention_generations.forget(w.hwnd);
+                    removed_processes.push(w.process.id);
                     false
                 }
             });
+            for process_id in removed_processes {
+                USER_APPS_MANAGER.forget_process_if_untracked(process_id);
+            }
 
             // Scan for windows that now qualify but were never tracked (e.g. style/frame
             // state was not settled at creation time).
@@ -81,11 +96,14 @@ impl UserAppsManager {
 
         match event {
             WinEvent::ObjectCreate | WinEvent::ObjectShow => {
-                if !is_interactable
-                    && is_interactable_window(&window)
-                    && USER_APPS_MANAGER.add_win(&window)
-                {
-                    Self::send(UserAppWinEvent::Added(window.address()));
+                if !is_interactable {
+                    if is_interactable_window(&window) {
+                        if USER_APPS_MANAGER.add_win(&window) {
+                            Self::send(UserAppWinEvent::Added(window.address()));
+                        }
+                    } else if matches!(window.get_frame_creator(), Ok(None)) {
+                        Self::recheck_frame_later(window);
+                    }
                 }
             }
             WinEvent::ObjectNameChange
@@ -148,19 +166,139 @@ impl UserAppsManager {
                 Self::send(UserAppWinEvent::Updated(window.address()));
             }
         }
+
+        if event == WinEvent::SystemForeground {
+            Self::refresh_unfocused_fullscreen(window.address());
+        }
+    }
+
+    /// Browsers are only considered fullscreen while focused, so the windows that were
+    /// fullscreen before the focus moved are re-evaluated.
+    fn refresh_unfocused_fullscreen(focused: isize) {
+        let mut updated = Vec::new();
+        USER_APPS_MANAGER.interactable_windows.for_each(|entry| {
+            if entry.hwnd != focused && entry.is_fullscreen && Self::refresh_fullscreen(entry) {
+                updated.push(entry.hwnd);
+            }
+        });
+        for hwnd in updated {
+            Self::send(UserAppWinEvent::Updated(hwnd));
+        }
+    }
+
+    /// UWP frames can be shown before their creator window is attached, so the frame
+    /// is re-evaluated a few times instead of waiting for the periodic revalidation.
+    /// Only one recheck runs per frame, repeated create/show events while pending are ignored.
+    fn recheck_frame_later(window: Window) {
+        static PENDING: LazyLock<SyncHashMap<isize, ()>> = LazyLock::new(SyncHashMap::default);
+
+        let hwnd = window.address();
+        if PENDING.upsert(hwnd, ()).is_some() {
+            return;
+        }
+
+        spawn_named_thread("UWP Frame Recheck", move || {
+            for _ in 0..3 {
+                std::thread::sleep(std::time::Duration::from_millis(300));
+                if !window.is_window() || USER_APPS_MANAGER.contains_win(&window) {
+                    break;
+                }
+                if is_interactable_window(&window) {
+                    if USER_APPS_MANAGER.add_win(&window) {
+                        Self::send(UserAppWinEvent::Added(hwnd));
+                    }
+                    break;
+                }
+            }
+            PENDING.remove(&hwnd);
+        });
+    }
+
+    /// Windows flashing via `FlashWindowEx` are notified by the shell hook as `HSHELL_FLASH`.
+    fn on_attention_requested(hwnd: isize) {
+        Self::request_attention(hwnd);
+    }
+
+    /// Marks the window as requiring attention and starts a new attention generation for it.
+    /// Returns the generation to be used by `clear_attention_if_current`, or None if the
+    /// window is not tracked.
+    pub fn request_attention(hwnd: isize) -> Option<u64> {
+        if !Self::set_requires_attention(hwnd, true) {
+            return None;
+        }
+        Some(USER_APPS_MANAGER.attention_generations.next(hwnd))
+    }
+
+    /// Clears the attention state only if no newer request was made since `generation`.
+    pub fn clear_attention_if_current(hwnd: isize, generation: u64) {
+        if USER_APPS_MANAGER
+            .attention_generations
+            .is_current(hwnd, generation)
+        {
+            Self::set_requires_attention(hwnd, false);
+        }
+    }
+
+    /// Marks/unmarks the window as requiring attention, focused windows are never marked
+    /// as focusing them already clears the state. Returns false if the window is not tracked.
+    pub fn set_requires_attention(hwnd: isize, requires_attention: bool) -> bool {
+        let mut found = false;
+        let mut changed = false;
+        USER_APPS_MANAGER.interactable_windows.for_each(|entry| {
+            if entry.hwnd != hwnd {
+                return;
+            }
+            found = true;
+            if entry.requires_attention == requires_attention
+                || (requires_attention && Window::from(hwnd).is_focused())
+            {
+                return;
+            }
+            entry.requires_attention = requires_attention;
+            changed = true;
+        });
+        if changed {
+            Self::send(UserAppWinEvent::Updated(hwnd));
+        }
+        found
+    }
+
+    /// Re-reads the topmost style, it is a cheap window long read. Returns true if it changed.
+    fn refresh_topmost(data: &mut UserAppWindow) -> bool {
+        let is_topmost = Window::from(data.hwnd).is_topmost();
+        if data.is_topmost == is_topmost {
+            return false;
+        }
+        data.is_topmost = is_topmost;
+        true
+    }
+
+    /// Returns true if the fullscreen state changed.
+    fn refresh_fullscreen(data: &mut UserAppWindow) -> bool {
+        let is_fullscreen = Window::from(data.hwnd).is_fullscreen();
+        if data.is_fullscreen == is_fullscreen {
+            return false;
+        }
+        data.is_fullscreen = is_fullscreen;
+        true
     }
 
     fn update_window_data(data: &mut UserAppWindow, event: WinEvent) -> bool {
         match event {
-            WinEvent::ObjectNameChange => {
-                let window = Window::from(data.hwnd);
-                data.title = window.title();
+            WinEvent::SynDebouncedNameChange => {
+                let title = Window::from(data.hwnd).title();
+                if data.title == title {
+                    return false;
+                }
+                data.short_title = short_title(&title, &data.app_name);
+                data.title = title;
                 true
             }
             WinEvent::SynDebouncedRectChange => {
                 let window = Window::from(data.hwnd);
                 data.is_zoomed = window.is_maximized();
                 data.is_fullscreen = window.is_fullscreen();
+                data.is_topmost = window.is_topmost();
                 data.rect = window.inner_rect().ok();
                 data.monitor = window.monitor().stable_id().unwrap_or_default();
                 true
@@ -168,8 +306,15 @@ impl UserAppsManager {
             WinEvent::SystemForeground => {
                 let now = now_millis();
                 data.last_foreground_at = now;
+                data.requires_attention = false;
+                Self::refresh_topmost(data);
+                // browsers playing a video on fullscreen are only fullscreen while focused
+                Self::refresh_fullscreen(data);
                 true
             }
+            // EVENT_OBJECT_REORDER is not always raised when a top level window toggles
+            // "always on top" via `SetWindowPos`, so location changes are checked too.
+            WinEvent::ObjectReorder | WinEvent::ObjectLocationChange => Self::refresh_topmost(data),
             WinEvent::SystemMinimizeStart => {
                 data.is_iconic = true;
                 true
@@ -183,6 +328,32 @@ impl UserAppsManager {
     }
 }
 
+/// Attention requests counter per window. It only grows while the window is tracked, so a
+/// timed clear scheduled for an older request never matches a newer one.
+#[derive(Default)]
+pub(super) struct AttentionGenerations(SyncHashMap<isize, u64>);
+
+impl AttentionGenerations {
+    /// Starts a new generation for the window and returns it.
+    fn next(&self, hwnd: isize) -> u64 {
+        self.0.get_or_default(hwnd, |generation| {
+            *generation += 1;
+            *generation
+        })
+    }
+
+    fn is_current(&self, hwnd: isize, generation: u64) -> bool {
+        self.0
+            .get(&hwnd, |current| *current == generation)
+            .unwrap_or(false)
+    }
+
+    /// To be called once the window is no longer tracked.
+    pub(super) fn forget(&self, hwnd: isize) {
+        self.0.remove(&hwnd);
+    }
+}
+
 /// The idea with this module is contain all the logic under the filteriong of windows
 /// that can be considered as applications windows, it means windows that are interactable
 /// for the users.
@@ -260,3 +431,34 @@ pub fn is_interactable_window(window: &Window) -> bool {
 
     true
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stale_timer_does_not_match_a_newer_request() {
+        let generations = AttentionGenerations::default();
+        let first = generations.next(1);
+        // the attention is cleared by focus or clear_app_attention, the counter is kept
+        let second = generations.next(1);
+        assert!(!generations.is_current(1, first));
+        assert!(generations.is_current(1, second));
+    }
+
+    #[test]
+    fn generations_are_per_window() {
+        let generations = AttentionGenerations::default();
+        let first = generations.next(1);
+        generations.next(2);
+        assert!(generations.is_current(1, first));
+    }
+
+    #[test]
+    fn forgotten_windows_have_no_current_generation() {
+        let generations = AttentionGenerations::default();
+        let first = generations.next(1);
+        generations.forget(1);
+        assert!(!generations.is_current(1, first));
+    }
+}
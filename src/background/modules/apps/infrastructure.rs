@@ -1,24 +1,115 @@
-use std::{collections::HashMap, sync::Once};
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    sync::{LazyLock, Once},
+    time::Duration,
+};
+
+use parking_lot::Mutex;
 
 use seelen_core::{
     handlers::SeelenEvent,
-    system_state::{FocusedApp, UserAppWindow, UserAppWindowColors, UserAppWindowPreview},
+    rect::Rect,
+    system_state::{
+        FocusedApp, JumpList, MonitorId, UserAppWindow, UserAppWindowColors, UserAppWindowPreview,
+        UserAppWindowsDelta,
+    },
+};
+use windows::Win32::{
+    Foundation::HWND,
+    UI::Shell::{IShellDispatch6, Shell},
 };
-use windows::Win32::UI::Shell::{IShellDispatch6, Shell};
 
 use crate::{
     app::emit_to_webviews,
     error::Result,
-    modules::apps::application::{previews::WinPreviewManager, UserAppsManager},
-    windows_api::{input::Mouse, window::Window, Com},
+    modules::apps::application::{
+        jump_list, live_thumbnails, previews::WinPreviewManager, UserAppWinEvent, UserAppsManager,
+    },
+    state::application::FULL_STATE,
+    utils::diagnostics::Counter,
+    windows_api::{input::Mouse, window::Window, Com, MonitorEnumerator},
 };
 
+/// Changes waiting to be flushed, keyed by window address, when batched updates are enabled.
+static PENDING_CHANGES: LazyLock<Mutex<HashMap<isize, UserAppWinEvent>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// time to wait for more changes before emitting them, roughly a frame.
+const BATCH_INTERVAL: Duration = Duration::from_millis(16);
+
+/// Coalesces the change of a window with the one already pending for it, None means that
+/// nothing has to be reported for the window.
+fn merge_batched_change(
+    previous: Option<UserAppWinEvent>,
+    event: UserAppWinEvent,
+) -> Option<UserAppWinEvent> {
+    match (previous, event) {
+        // a window that lived only during the batch is never reported
+        (Some(UserAppWinEvent::Added(_)), UserAppWinEvent::Removed(_)) => None,
+        (Some(UserAppWinEvent::Added(addr)), _) => Some(UserAppWinEvent::Added(addr)),
+        (Some(UserAppWinEvent::Removed(_)), UserAppWinEvent::Added(addr)) => {
+            Some(UserAppWinEvent::Updated(addr))
+        }
+        (_, event) => Some(event),
+    }
+}
+
+fn queue_batched_change(event: UserAppWinEvent) {
+    let mut pending = PENDING_CHANGES.lock();
+    let schedule_flush = pending.is_empty();
+
+    let addr = match event {
+        UserAppWinEvent::Added(addr)
+        | UserAppWinEvent::Updated(addr)
+        | UserAppWinEvent::Removed(addr) => addr,
+    };
+
+    match merge_batched_change(pending.get(&addr).copied(), event) {
+        Some(event) => pending.insert(addr, event),
+        None => pending.remove(&addr),
+    };
+
+    if schedule_flush {
+        std::thread::spawn(|| {
+            std::thread::sleep(BATCH_INTERVAL);
+            flush_batched_changes();
+        });
+    }
+}
+
+fn flush_batched_changes() {
+    let changes = std::mem::take(&mut *PENDING_CHANGES.lock());
+    if changes.is_empty() {
+        return;
+    }
+
+    let windows = UserAppsManager::instance().interactable_windows.to_vec();
+    let find = |addr: isize| windows.iter().find(|w| w.hwnd == addr).cloned();
+
+    let mut delta = UserAppWindowsDelta::default();
+    for event in changes.into_values() {
+        match event {
+            UserAppWinEvent::Added(addr) => delta.added.extend(find(addr)),
+            UserAppWinEvent::Updated(addr) => delta.updated.extend(find(addr)),
+            UserAppWinEvent::Removed(addr) => delta.removed.push(addr),
+        }
+    }
+
+    emit_to_webviews(SeelenEvent::UserAppWindowsDelta, delta);
+}
+
 /// Lazy initialization wrapper that registers Tauri events on first access
 /// This keeps Tauri logic separate from system logic while ensuring lazy initialization
 fn get_apps_manager() -> &'static UserAppsManager {
     static TAURI_EVENT_REGISTRATION: Once = Once::new();
     TAURI_EVENT_REGISTRATION.call_once(|| {
-        UserAppsManager::subscribe(|_event| {
+        UserAppsManager::subscribe(|event| {
+            Counter::from(event).increment();
+            if FULL_STATE.load().settings.by_widget.weg.batched_updates {
+                queue_batched_change(event);
+                return;
+            }
             emit_to_webviews(
                 SeelenEvent::UserAppWindowsChanged,
                 UserAppsManager::instance().interactable_windows.to_vec(),
@@ -48,7 +139,7 @@ fn get_apps_manager() -> &'static UserAppsManager {
 
 #[tauri::command(async)]
 pub fn get_focused_app() -> FocusedApp {
-    Window::get_foregrounded().as_focused_app_information()
+    UserAppsManager::instance().focused_app_information(&Window::get_foregrounded())
 }
 
 #[tauri::command(async)]
@@ -82,12 +173,138 @@ pub fn get_user_app_windows_previews() -> HashMap<isize, UserAppWindowPreview> {
     WinPreviewManager::instance().get_previews()
 }
 
+/// Max amount of previews returned for a single group, to bound the payload size.
+const MAX_GROUP_PREVIEWS: usize = 12;
+
+/// Previews of the windows of the given executable, the most recently focused first.
+#[tauri::command(async)]
+pub fn get_group_previews(exe: PathBuf) -> Result<Vec<(isize, UserAppWindowPreview)>> {
+    let mut windows: Vec<UserAppWindow> = get_apps_manager()
+        .interactable_windows
+        .to_vec()
+        .into_iter()
+        .filter(|w| {
+            w.process
+                .path
+                .as_ref()
+                .is_some_and(|p| p.as_os_str().eq_ignore_ascii_case(exe.as_os_str()))
+        })
+        .collect();
+
+    if windows.is_empty() {
+        return Err(format!("There are no windows for {}", exe.display()).into());
+    }
+
+    windows.sort_by_key(|w| std::cmp::Reverse(w.last_foreground_at));
+    let windows: Vec<isize> = windows
+        .iter()
+        .take(MAX_GROUP_PREVIEWS)
+        .map(|w| w.hwnd)
+        .collect();
+    Ok(WinPreviewManager::instance().get_previews_of(&windows))
+}
+
 #[tauri::command(async)]
 pub fn get_user_app_windows_colors() -> HashMap<isize, UserAppWindowColors> {
     get_apps_manager();
     WinPreviewManager::instance().get_colors()
 }
 
+/// Draws a live thumbnail of the window over the given rect of the caller webview,
+/// rect is in physical pixels relative to the webview.
+#[tauri::command(async)]
+pub fn set_live_thumbnail(webview: tauri::WebviewWindow, hwnd: isize, rect: Rect) -> Result<()> {
+    let source = Window::from(hwnd);
+    if !source.is_window() {
+        return Err("Window not found".into());
+    }
+    live_thumbnails::set_live_thumbnail(HWND(webview.hwnd()?.0), source.hwnd(), &rect)
+}
+
+#[tauri::command(async)]
+pub fn remove_live_thumbnail(webview: tauri::WebviewWindow, hwnd: isize) -> Result<()> {
+    live_thumbnails::remove_live_thumbnail(HWND(webview.hwnd()?.0), Window::from(hwnd).hwnd())
+}
+
+#[tauri::command(async)]
+pub fn get_jump_list(umid: String) -> Result<JumpList> {
+    jump_list::get_jump_list(&umid)
+}
+
+#[tauri::command(async)]
+pub fn activate_jump_item(umid: String, path: PathBuf) -> Result<()> {
+    jump_list::activate_jump_item(&umid, &path)
+}
+
+/// Draws attention to the window on the dock as if it had called `FlashWindowEx`. The state is
+/// cleared when the window is focused, after `duration_ms` or via `clear_app_attention`.
+#[tauri::command(async)]
+pub fn request_app_attention(hwnd: isize, duration_ms: Option<u32>) -> Result<()> {
+    get_apps_manager();
+    let Some(generation) = UserAppsManager::request_attention(hwnd) else {
+        return Err("Window not found".into());
+    };
+    if let Some(duration_ms) = duration_ms {
+        clear_attention_later(vec![(hwnd, generation)], duration_ms);
+    }
+    Ok(())
+}
+
+/// Same as `request_app_attention` but for all the windows of the given executable.
+#[tauri::command(async)]
+pub fn request_app_attention_by_exe(path: PathBuf, duration_ms: Option<u32>) -> Result<()> {
+    let windows: Vec<isize> = get_apps_manager()
+        .interactable_windows
+        .to_vec()
+        .into_iter()
+        .filter(|w| {
+            w.process
+                .path
+                .as_ref()
+                .is_some_and(|p| p.as_os_str().eq_ignore_ascii_case(path.as_os_str()))
+        })
+        .map(|w| w.hwnd)
+        .collect();
+
+    if windows.is_empty() {
+        return Err("No windows found for the executable".into());
+    }
+    let requests: Vec<(isize, u64)> = windows
+        .into_iter()
+        .filter_map(|hwnd| UserAppsManager::request_attention(hwnd).map(|g| (hwnd, g)))
+        .collect();
+    if let Some(duration_ms) = duration_ms {
+        clear_attention_later(requests, duration_ms);
+    }
+    Ok(())
+}
+
+#[tauri::command(async)]
+pub fn clear_app_attention(hwnd: isize) -> Result<()> {
+    get_apps_manager();
+    UserAppsManager::set_requires_attention(hwnd, false);
+    Ok(())
+}
+
+/// The clear is skipped for windows that got a newer attention request in the meantime.
+fn clear_attention_later(requests: Vec<(isize, u64)>, duration_ms: u32) {
+    std::thread::spawn(move || {
+        std::thread::sleep(std::time::Duration::from_millis(duration_ms as u64));
+        for (hwnd, generation) in requests {
+            UserAppsManager::clear_attention_if_current(hwnd, generation);
+        }
+    });
+}
+
+#[tauri::command(async)]
+pub fn send_window_to_monitor(hwnd: isize, monitor_id: MonitorId) -> Result<()> {
+    let target = MonitorEnumerator::enumerate_win32()?
+        .into_iter()
+        .find(|m| m.stable_id().is_ok_and(|id| id == monitor_id))
+        .ok_or_else(|| format!("Monitor {monitor_id} not found"))?;
+    Window::from(hwnd).move_to_monitor(&target)
+}
+
 /// This function is called show_desktop but acts more like minimize_all
 #[tauri::command(async)]
 pub fn show_desktop() -> Result<()> {
@@ -97,3 +314,44 @@ pub fn show_desktop() -> Result<()> {
         Ok(())
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_change_of_a_window_is_kept() {
+        let event = UserAppWinEvent::Updated(1);
+        assert_eq!(merge_batched_change(None, event), Some(event));
+    }
+
+    #[test]
+    fn windows_added_and_removed_in_the_same_batch_are_not_reported() {
+        let merged =
+            merge_batched_change(Some(UserAppWinEvent::Added(1)), UserAppWinEvent::Removed(1));
+        assert_eq!(merged, None);
+    }
+
+    #[test]
+    fn updates_of_added_windows_are_reported_as_added() {
+        let merged =
+            merge_batched_change(Some(UserAppWinEvent::Added(1)), UserAppWinEvent::Updated(1));
+        assert_eq!(merged, Some(UserAppWinEvent::Added(1)));
+    }
+
+    #[test]
+    fn windows_removed_and_added_again_are_reported_as_updated() {
+        let merged =
+            merge_batched_change(Some(UserAppWinEvent::Removed(1)), UserAppWinEvent::Added(1));
+        assert_eq!(merged, Some(UserAppWinEvent::Updated(1)));
+    }
+
+    #[test]
+    fn removal_replaces_pending_updates() {
+        let merged = merge_batched_change(
+            Some(UserAppWinEvent::Updated(1)),
+            UserAppWinEvent::Removed(1),
+        );
+        assert_eq!(merged, Some(UserAppWinEvent::Removed(1)));
+    }
+}
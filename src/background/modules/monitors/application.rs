@@ -9,7 +9,7 @@ use windows::{
         DisplayManagerPathsFailedOrInvalidatedEventArgs,
     },
     Foundation::TypedEventHandler,
-    Win32::UI::WindowsAndMessaging::WM_DISPLAYCHANGE,
+    Win32::UI::WindowsAndMessaging::{WM_DISPLAYCHANGE, WM_SETTINGCHANGE},
 };
 
 use crate::{
@@ -24,6 +24,8 @@ use crate::{
 
 pub struct MonitorManager {
     state_views: SyncHashMap<MonitorId, DisplayView>,
+    /// last known scale factor of each monitor, used to detect scale changes on any monitor
+    scale_factors: SyncHashMap<MonitorId, f64>,
     /// DisplayManager manages critical hardware so be sure to be correctly used, or will make the app crash.
     /// https://learn.microsoft.com/en-us/uwp/api/windows.devices.display.core.displaymanager
     display_manager: DisplayManager,
@@ -40,6 +42,8 @@ pub enum MonitorManagerEvent {
     /// the id used is the view primary target id
     ViewRemoved(MonitorId),
     ViewsChanged,
+    /// the scale factor of this monitor changed without any resolution/topology change
+    ScaleChanged(MonitorId),
 }
 
 event_manager!(MonitorManager, MonitorManagerEvent);
@@ -63,6 +67,7 @@ impl MonitorManager {
         Ok(MonitorManager {
             display_manager,
             state_views: SyncHashMap::from(state_views),
+            scale_factors: SyncHashMap::from(Self::read_scale_factors()),
             enabled_token: None,
             disabled_token: None,
             changed_token: None,
@@ -117,6 +122,12 @@ impl MonitorManager {
                 log::debug!("Displays changed");
                 Self::request_display_state_refresh();
             }
+            // scale changes don't modify the resolution so WM_DISPLAYCHANGE is not sent, and
+            // WM_DPICHANGED only reaches windows on the changed monitor, so on setting changes
+            // the scale of every monitor is re-read and compared with the cached one.
+            if event == WM_SETTINGCHANGE {
+                Self::request_scale_check();
+            }
             Ok(())
         });
         Ok(())
@@ -132,6 +143,10 @@ impl MonitorManager {
             debounce(
                 |_| {
                     MonitorManager::check_for_display_changes().log_error();
+                    // ViewsChanged already carries the new scales
+                    MonitorManager::instance()
+                        .scale_factors
+                        .replace(MonitorManager::read_scale_factors());
                     MonitorManager::send(MonitorManagerEvent::ViewsChanged);
                 },
                 Duration::from_millis(400),
@@ -140,6 +155,41 @@ impl MonitorManager {
         DEBOUNCER.call(());
     }
 
+    fn request_scale_check() {
+        static DEBOUNCER: LazyLock<Debounce<()>> = LazyLock::new(|| {
+            debounce(
+                |_| MonitorManager::check_for_scale_changes(),
+                Duration::from_millis(400),
+            )
+        });
+        DEBOUNCER.call(());
+    }
+
+    fn read_scale_factors() -> HashMap<MonitorId, f64> {
+        let mut scales = HashMap::new();
+        for monitor in MonitorEnumerator::enumerate_win32().unwrap_or_default() {
+            if let (Ok(id), Ok(scale)) = (monitor.stable_id(), monitor.scale_factor()) {
+                scales.insert(id, scale);
+            }
+        }
+        scales
+    }
+
+    /// Emits `ScaleChanged` only for the monitors whose scale differs from the cached one,
+    /// added/removed monitors are reported by the display state refresh instead.
+    fn check_for_scale_changes() {
+        let current = Self::read_scale_factors();
+        let old = Self::instance().scale_factors.to_hash_map();
+        Self::instance().scale_factors.replace(current.clone());
+
+        for (id, scale) in current {
+            if old.get(&id).is_some_and(|old_scale| *old_scale != scale) {
+                log::debug!("Scale factor of {id} changed to {scale}");
+                Self::send(MonitorManagerEvent::ScaleChanged(id));
+            }
+        }
+    }
+
     /// Polls the Win32 monitor enumeration until it reflects `expected_count` monitors
     /// with non-degenerate rects, or gives up after a few attempts. Runs on the debounce's
     /// own background thread, so blocking here is safe.
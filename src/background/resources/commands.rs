@@ -7,6 +7,7 @@ use seelen_core::{
 
 use crate::{
     error::{Result, ResultLogExt},
+    modules::apps::application::{request_app_icon, USER_APPS_MANAGER},
     resources::RESOURCES,
     utils::icon_extractor::queue::IconExtractor,
 };
@@ -120,8 +121,20 @@ pub async fn state_delete_cached_icons() -> Result<()> {
     if let Some(pack) = pack {
         pack.delete().await?;
     }
+    IconExtractor::instance().clear_extracted();
     IconExtractor::instance().clear_failures();
     RESOURCES.ensure_system_icon_pack()?;
     RESOURCES.emit_icon_packs();
     Ok(())
 }
+
+/// Deletes the cached icons and queues the extraction of the icons of every open app,
+/// intended for when icons got stuck wrong (e.g. after changing the scale or overrides).
+#[tauri::command(async)]
+pub async fn state_refresh_all_icons() -> Result<()> {
+    state_delete_cached_icons().await?;
+    for app in USER_APPS_MANAGER.interactable_windows.to_vec() {
+        request_app_icon(&app);
+    }
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use std::{
+    collections::HashSet,
     path::{Path, PathBuf},
     sync::LazyLock,
 };
@@ -71,6 +72,8 @@ impl ResourceManager {
         ensure_icon("start-menu.svg")?;
         ensure_icon("folder.svg")?;
         ensure_icon("desktop.svg")?;
+        ensure_icon("console.svg")?;
+        ensure_icon("uwp-tile.svg")?;
 
         ensure_icon("trash_bin_empty.png")?;
         ensure_icon("trash_bin_full.png")?;
@@ -122,6 +125,24 @@ impl ResourceManager {
             },
         }));
 
+        system_pack.add_entry(IconPackEntry::Custom(CustomIconPackEntry {
+            key: "@seelen/weg::fallback-console".to_owned(),
+            icon: Icon {
+                base: Some("console.svg".to_owned()),
+                is_aproximately_square: true,
+                ..Default::default()
+            },
+        }));
+
+        system_pack.add_entry(IconPackEntry::Custom(CustomIconPackEntry {
+            key: "@seelen/weg::fallback-uwp".to_owned(),
+            icon: Icon {
+                base: Some("uwp-tile.svg".to_owned()),
+                is_aproximately_square: true,
+                ..Default::default()
+            },
+        }));
+
         system_pack.add_entry(IconPackEntry::Custom(CustomIconPackEntry {
             key: "defaultPlayerThumbnail".to_owned(),
             icon: Icon {
@@ -150,6 +171,47 @@ impl ResourceManager {
         }));
     }
 
+    /// Removes the generated icons that are no longer referenced by any entry of the pack.
+    fn remove_unreferenced_icons(system_pack: &IconPack, sys_icons_path: &Path) -> Result<()> {
+        let mut referenced = HashSet::new();
+        let mut add_icon = |icon: &Icon| {
+            for file in [&icon.base, &icon.light, &icon.dark, &icon.mask]
+                .into_iter()
+                .flatten()
+            {
+                referenced.insert(file.clone());
+            }
+        };
+
+        if let Some(missing) = &system_pack.missing {
+            add_icon(missing);
+        }
+        for entry in &system_pack.entries {
+            match entry {
+                IconPackEntry::Unique(u) => {
+                    if let Some(icon) = &u.icon {
+                        add_icon(icon);
+                    }
+                }
+                IconPackEntry::Shared(s) => add_icon(&s.icon),
+                IconPackEntry::Custom(c) => add_icon(&c.icon),
+            }
+        }
+
+        for entry in std::fs::read_dir(sys_icons_path)?.flatten() {
+            let path = entry.path();
+            // default icons are not webp, so only generated ones are affected
+            if path.extension().is_none_or(|ext| ext != "webp") {
+                continue;
+            }
+            let filename = entry.file_name().to_string_lossy().to_string();
+            if !referenced.contains(&filename) {
+                std::fs::remove_file(&path).log_error();
+            }
+        }
+        Ok(())
+    }
+
     pub fn ensure_system_icon_pack(&self) -> Result<()> {
         let sys_icons_path = SEELEN_COMMON.system_icon_pack_path();
 
@@ -172,6 +234,7 @@ impl ResourceManager {
         let system_pack = guard.as_mut().expect("System icon pack should exist");
         Self::sanitize_default_entries(system_pack);
         Self::sanitize_default_icons(sys_icons_path)?;
+        Self::remove_unreferenced_icons(system_pack, sys_icons_path).log_error();
 
         self.request_save_system_icon_pack();
         Ok(())
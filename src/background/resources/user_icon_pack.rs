@@ -30,6 +30,7 @@ fn with_icon(entry: IconPackEntry, icon_rel_path: String) -> IconPackEntry {
                 dark: None,
                 mask: None,
                 is_aproximately_square: existing.is_aproximately_square,
+                dominant_color: None,
             });
             u.redirect = None;
             IconPackEntry::Unique(u)
@@ -41,6 +42,7 @@ fn with_icon(entry: IconPackEntry, icon_rel_path: String) -> IconPackEntry {
                 dark: None,
                 mask: None,
                 is_aproximately_square: s.icon.is_aproximately_square,
+                dominant_color: None,
             };
             IconPackEntry::Shared(s)
         }
@@ -51,6 +53,7 @@ fn with_icon(entry: IconPackEntry, icon_rel_path: String) -> IconPackEntry {
                 dark: None,
                 mask: None,
                 is_aproximately_square: c.icon.is_aproximately_square,
+                dominant_color: None,
             };
             IconPackEntry::Custom(c)
         }
@@ -0,0 +1,30 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+
+/// Operations counted since the app started. Counting is only compiled in with the
+/// `diagnostics` feature, otherwise every counter stays at zero.
+#[derive(Debug, Clone, Copy)]
+pub enum Counter {
+    WindowAdded,
+    WindowRemoved,
+    WindowUpdated,
+    IconCacheHit,
+    IconCacheMiss,
+    OverlapToggled,
+}
+
+static COUNTERS: [AtomicU32; 6] = [const { AtomicU32::new(0) }; 6];
+
+impl Counter {
+    pub fn increment(self) {
+        #[cfg(feature = "diagnostics")]
+        COUNTERS[self as usize].fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn get(self) -> u32 {
+        COUNTERS[self as usize].load(Ordering::Relaxed)
+    }
+}
+
+pub fn is_enabled() -> bool {
+    cfg!(feature = "diagnostics")
+}
@@ -8,16 +8,22 @@ use std::arch::x86_64::{
 #[cfg(target_arch = "aarch64")]
 use std::arch::aarch64::{uint8x16_t, vld1q_u8, vqtbl1q_u8, vst1q_u8};
 
+use std::collections::HashMap;
 use std::io::BufRead;
 use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
 
-use image::{GenericImageView, ImageBuffer, RgbaImage};
+use image::codecs::ico::{IcoEncoder, IcoFrame};
+use image::{ExtendedColorType, GenericImageView, ImageBuffer, RgbaImage};
+use parking_lot::Mutex;
+use slu_utils::checksums::calculate_sha256;
 
 use queue::{IconExtractor, IconExtractorRequest};
 use windows::Win32::Storage::FileSystem::FILE_FLAGS_AND_ATTRIBUTES;
 use windows::Win32::UI::Controls::{IImageList, ILD_TRANSPARENT};
 use windows::Win32::UI::Shell::{
-    SHGetFileInfoW, SHGetImageList, SHFILEINFOW, SHGFI_SYSICONINDEX, SHIL_JUMBO,
+    SHDefExtractIconW, SHGetFileInfoW, SHGetImageList, SHFILEINFOW, SHGFI_SYSICONINDEX,
+    SHIL_EXTRALARGE, SHIL_JUMBO,
 };
 use windows::Win32::{
     Graphics::Gdi::{
@@ -37,7 +43,8 @@ use crate::{
     error::Result,
     modules::{apps::application::msix::MsixAppsManager, start::application::StartMenuManager},
     resources::RESOURCES,
-    utils::{constants::SEELEN_COMMON, date_based_hex_id},
+    state::application::FULL_STATE,
+    utils::{constants::SEELEN_COMMON, diagnostics::Counter},
     windows_api::{string_utils::WindowsString, types::AppUserModelId, WindowsApi},
 };
 
@@ -277,19 +284,84 @@ pub fn crop_transparent_borders(rgba_image: &RgbaImage) -> RgbaImage {
         .to_image()
 }
 
-pub fn extract_icon_from_module(path: &Path, index: i32) -> Result<RgbaImage> {
+/// Size requested to the shell when extracting icons from modules, the biggest size supported
+/// by the icon group is 256x256, if not available windows will return the nearest bigger size.
+const JUMBO_ICON_SIZE: u32 = 256;
+const SMALL_ICON_SIZE: u32 = 16;
+
+/// Extracts the icon at `index` of the module requesting the given `size`, if the icon group
+/// does not have it windows returns the nearest bigger one. Transparent borders are kept.
+pub fn extract_icon_from_module(path: &Path, index: i32, size: u32) -> Result<RgbaImage> {
     let path = WindowsString::from(path);
     unsafe {
         let mut hicon = HICON::default();
-        let extracted = ExtractIconExW(path.as_pcwstr(), index, Some(&mut hicon), None, 1);
-        if extracted == 0 || hicon.is_invalid() {
-            return Err(format!("Icon index {index} not found").into());
+        // nIconSize: low word is the large icon size, high word is the small icon size
+        let sizes = size | (SMALL_ICON_SIZE << 16);
+        let high_res = SHDefExtractIconW(path.as_pcwstr(), index, 0, Some(&mut hicon), None, sizes);
+
+        // fallback to the default large icon (commonly 32x32)
+        if high_res.is_err() || hicon.is_invalid() {
+            hicon = HICON::default();
+            let extracted = ExtractIconExW(path.as_pcwstr(), index, Some(&mut hicon), None, 1);
+            if extracted == 0 || hicon.is_invalid() {
+                return Err(format!("Icon index {index} not found").into());
+            }
         }
         let hicon = Owned::new(hicon);
+        convert_hicon_to_rgba_image(&hicon)
+    }
+}
 
-        let image = crop_transparent_borders(&convert_hicon_to_rgba_image(&hicon)?);
-        Ok(image)
+/// Sizes included on the exported `.ico` files.
+const ICO_SIZES: [u32; 4] = [16, 32, 48, 256];
+
+/// Writes the icon group of the executable as a multi-size `.ico` (16/32/48/256) and returns
+/// its path. Intended for tooling/exports, the dock keeps using the webp from the icon pack.
+///
+/// Files are cached on their own folder, named by a hash of the executable path, size and
+/// modification time, so updated executables get a new file.
+pub fn extract_icon_multi(path: &Path) -> Result<PathBuf> {
+    if !path.is_file() {
+        return Err(format!("File not found: {}", path.display()).into());
     }
+
+    let metadata = std::fs::metadata(path)?;
+    let modified = metadata
+        .modified()?
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let key = format!(
+        "{}|{}|{modified}",
+        path.to_string_lossy().to_lowercase(),
+        metadata.len()
+    );
+    let folder = SEELEN_COMMON.app_cache_dir().join("gen-icon-ico");
+    let ico_path = folder.join(format!("{}.ico", &calculate_sha256(key.as_bytes())[..16]));
+    if ico_path.exists() {
+        return Ok(ico_path);
+    }
+
+    let mut frames = Vec::new();
+    for size in ICO_SIZES {
+        // every frame keeps the same layout, so borders are not cropped
+        let mut image = extract_icon_from_module(path, 0, size)?;
+        if image.width() != size || image.height() != size {
+            image =
+                image::imageops::resize(&image, size, size, image::imageops::FilterType::Lanczos3);
+        }
+        frames.push(IcoFrame::as_png(
+            image.as_raw(),
+            size,
+            size,
+            ExtendedColorType::Rgba8,
+        )?);
+    }
+
+    std::fs::create_dir_all(&folder)?;
+    let file = std::fs::File::create(&ico_path)?;
+    IcoEncoder::new(std::io::BufWriter::new(file)).encode_images(&frames)?;
+    Ok(ico_path)
 }
 
 pub fn get_shell_icon(path: &Path) -> Result<RgbaImage> {
@@ -320,17 +392,27 @@ pub fn get_shell_icon(path: &Path) -> Result<RgbaImage> {
             return Err("Icon index is 0".into());
         }
 
-        let image_list: IImageList = SHGetImageList(SHIL_JUMBO as i32)?;
         // if 256x256 icon is not available, will use the icons with the most color depth and size
         // this is useful for some icons where color depth is less than 32,
         // example: icon of 124x124 16bits and other 64x64 32bits this will return the 32bits icon
         // color depth is prioritized over size
-        let icon = Owned::new(image_list.GetIcon(file_info.iIcon, ILD_TRANSPARENT.0)?);
+        let icon = match get_icon_from_image_list(SHIL_JUMBO, file_info.iIcon) {
+            Ok(icon) => icon,
+            // jumbo list is not available on some systems/sessions (e.g. remote desktop)
+            Err(_) => get_icon_from_image_list(SHIL_EXTRALARGE, file_info.iIcon)?,
+        };
         let image = crop_transparent_borders(&convert_hicon_to_rgba_image(&icon)?);
         Ok(image)
     }
 }
 
+/// ### Safety
+/// `index` should be a valid system icon index, as returned by `SHGetFileInfoW`.
+unsafe fn get_icon_from_image_list(list: u32, index: i32) -> Result<Owned<HICON>> {
+    let image_list: IImageList = SHGetImageList(list as i32)?;
+    Ok(Owned::new(image_list.GetIcon(index, ILD_TRANSPARENT.0)?))
+}
+
 fn get_icon_from_url_file(path: &Path) -> Result<RgbaImage> {
     let file = std::fs::File::open(path)?;
     let reader = std::io::BufReader::new(file);
@@ -349,7 +431,8 @@ fn get_icon_from_url_file(path: &Path) -> Result<RgbaImage> {
     }
 
     let icon_file = icon_file.ok_or("Url does not have IconFile")?;
-    extract_icon_from_module(&icon_file, icon_idx.unwrap_or(0))
+    let image = extract_icon_from_module(&icon_file, icon_idx.unwrap_or(0), JUMBO_ICON_SIZE)?;
+    Ok(crop_transparent_borders(&image))
 }
 
 /// Parses Windows-style `path,index` icon notation (e.g. `C:\foo\bar.ico,0`).
@@ -382,31 +465,139 @@ fn _extract_and_save_icon_from_module_with_index(
         return Ok(());
     }
 
-    let filestem = path.file_stem().ok_or("Failed to get file stem")?;
-    let gen_icon_filename = format!(
-        "{}_{}.webp",
-        filestem.to_string_lossy(),
-        date_based_hex_id()
-    );
-
     log::trace!("Extracting icon (index {index}) for {:?}", path.file_name());
 
-    let image = extract_icon_from_module(path, index)?;
+    let image = extract_icon_from_module(path, index, JUMBO_ICON_SIZE)?;
     let image = crop_transparent_borders(&image);
 
     let gen_icon = Icon {
-        base: Some(gen_icon_filename.clone()),
+        base: Some(save_on_system_icon_pack(&image)?),
         is_aproximately_square: is_aproximately_a_square(&image),
+        dominant_color: get_dominant_color(&image),
         ..Default::default()
     };
+    RESOURCES.add_system_app_icon(None, Some(key), gen_icon);
+    Ok(())
+}
+
+/// returns the icon defined by the user for the executable, if any.
+fn get_user_icon_override(origin: &Path) -> Option<PathBuf> {
+    let origin = origin.to_string_lossy().to_lowercase();
+    let state = FULL_STATE.load();
+    state
+        .settings
+        .by_widget
+        .weg
+        .icon_overrides
+        .iter()
+        .find(|(exe, _)| exe.to_lowercase() == origin)
+        .map(|(_, icon)| icon.clone())
+}
+
+static APPLIED_ICON_OVERRIDES: LazyLock<Mutex<HashMap<String, PathBuf>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Re-extracts the icons of the executables whose override was added, changed or removed.
+pub fn sync_user_icon_overrides(overrides: &HashMap<String, PathBuf>) {
+    let mut applied = APPLIED_ICON_OVERRIDES.lock();
+    if *applied == *overrides {
+        return;
+    }
+
+    let changed: Vec<PathBuf> = overrides
+        .iter()
+        .filter(|(exe, icon)| applied.get(*exe) != Some(icon))
+        .map(|(exe, _)| exe)
+        .chain(applied.keys().filter(|exe| !overrides.contains_key(*exe)))
+        .map(PathBuf::from)
+        .collect();
+    *applied = overrides.clone();
+    drop(applied);
+
+    for exe in changed {
+        IconExtractor::instance().refresh(&exe);
+    }
+}
+
+/// extensions in which a user override can be stored on the system icon pack.
+const USER_ICON_OVERRIDE_EXTENSIONS: [&str; 2] = ["webp", "svg"];
+
+/// overrides are always saved with the same name, so they are replaced instead of accumulated.
+/// the name is also stored on the icon pack, so it must be stable between toolchains.
+fn user_icon_override_filename(origin: &Path, extension: &str) -> String {
+    let hash = calculate_sha256(origin.to_string_lossy().to_lowercase().as_bytes());
+    format!("override_{}.{extension}", &hash[..16])
+}
 
+/// Overrides can point to an image or to an icon resource of a module, like a companion dll
+/// of the app, using the `path,index` notation (e.g. `C:\app\icons.dll,3`).
+fn load_user_icon_override_image(icon_path: &Path) -> Result<RgbaImage> {
+    if let Some((module, index)) = parse_path_with_icon_index(icon_path) {
+        return extract_icon_from_module(&module, index, JUMBO_ICON_SIZE);
+    }
+
+    let is_module = icon_path.extension().is_some_and(|ext| {
+        ["exe", "dll"]
+            .iter()
+            .any(|module_ext| ext.eq_ignore_ascii_case(module_ext))
+    });
+    if is_module {
+        return extract_icon_from_module(icon_path, 0, JUMBO_ICON_SIZE);
+    }
+
+    Ok(image::open(icon_path)?.to_rgba8())
+}
+
+fn _save_user_icon_override(origin: &Path, icon_path: &Path) -> Result<()> {
+    // the previous override could have been stored with another extension
+    for ext in USER_ICON_OVERRIDE_EXTENSIONS {
+        let _ = std::fs::remove_file(
+            SEELEN_COMMON
+                .system_icon_pack_path()
+                .join(user_icon_override_filename(origin, ext)),
+        );
+    }
+
+    let is_svg = icon_path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("svg"));
+
+    // svg files are used as they are, the webview will render them crisp at any scale/dpi.
+    if is_svg {
+        let gen_icon_filename = user_icon_override_filename(origin, "svg");
+        std::fs::copy(
+            icon_path,
+            SEELEN_COMMON
+                .system_icon_pack_path()
+                .join(&gen_icon_filename),
+        )?;
+
+        let gen_icon = Icon {
+            base: Some(gen_icon_filename),
+            ..Default::default()
+        };
+        RESOURCES.add_system_app_icon(None, Some(origin), gen_icon);
+        return Ok(());
+    }
+
+    let image = load_user_icon_override_image(icon_path)?;
+    let image = crop_transparent_borders(&image);
+
+    let gen_icon_filename = user_icon_override_filename(origin, "webp");
     save_as_webp(
         &image,
         &SEELEN_COMMON
             .system_icon_pack_path()
             .join(&gen_icon_filename),
     )?;
-    RESOURCES.add_system_app_icon(None, Some(key), gen_icon);
+
+    let gen_icon = Icon {
+        base: Some(gen_icon_filename),
+        is_aproximately_square: is_aproximately_a_square(&image),
+        dominant_color: get_dominant_color(&image),
+        ..Default::default()
+    };
+    RESOURCES.add_system_app_icon(None, Some(origin), gen_icon);
     Ok(())
 }
 
@@ -439,48 +630,58 @@ fn _extract_and_save_icon_from_file(origin: &Path) -> Result<()> {
     let is_lnk_file = origin_ext == "lnk";
     let is_url_file = origin_ext == "url";
 
+    // user overrides are consulted before doing any extraction
+    let mut was_overridden = false;
+    if is_exe_file {
+        if let Some(icon_path) = get_user_icon_override(origin) {
+            return _save_user_icon_override(origin, &icon_path);
+        }
+
+        // the override was removed so the cached icon should be regenerated
+        for ext in USER_ICON_OVERRIDE_EXTENSIONS {
+            let override_path = SEELEN_COMMON
+                .system_icon_pack_path()
+                .join(user_icon_override_filename(origin, ext));
+            if override_path.exists() {
+                std::fs::remove_file(&override_path)?;
+                was_overridden = true;
+            }
+        }
+    }
+
     if is_exe_file || is_lnk_file || is_url_file {
-        if RESOURCES.has_app_icon(None, Some(origin)) {
+        if !was_overridden && RESOURCES.has_app_icon(None, Some(origin)) {
+            Counter::IconCacheHit.increment();
             return Ok(());
         }
     } else if RESOURCES.has_shared_file_icon(origin) {
+        Counter::IconCacheHit.increment();
         return Ok(());
     }
+    Counter::IconCacheMiss.increment();
 
     let file_name = origin.file_name().ok_or("Failed to get file name")?;
-    let filestem = origin.file_stem().ok_or("Failed to get file stem")?;
-
-    let gen_icon_filename = format!(
-        "{}_{}.webp",
-        filestem.to_string_lossy(),
-        date_based_hex_id()
-    );
-    let mut gen_icon = Icon {
-        base: Some(gen_icon_filename.clone()),
-        ..Default::default()
-    };
-
     log::trace!("Extracting icon for {file_name:?}");
 
     if origin_ext == "url" {
         let image = get_icon_from_url_file(origin)?;
-        gen_icon.is_aproximately_square = is_aproximately_a_square(&image);
-        save_as_webp(
-            &image,
-            &SEELEN_COMMON
-                .system_icon_pack_path()
-                .join(&gen_icon_filename),
-        )?;
+        let gen_icon = Icon {
+            base: Some(save_on_system_icon_pack(&image)?),
+            is_aproximately_square: is_aproximately_a_square(&image),
+            dominant_color: get_dominant_color(&image),
+            ..Default::default()
+        };
         RESOURCES.add_system_app_icon(None, Some(origin), gen_icon);
         return Ok(());
     }
 
     let mut umid = None;
+    let mut custom_icon = None;
     if is_lnk_file {
         umid = WindowsApi::get_file_umid(origin).ok();
-        let has_custom_icon = WindowsApi::resolve_lnk_custom_icon_path(origin).is_ok();
+        custom_icon = WindowsApi::resolve_lnk_custom_icon_path(origin).ok();
 
-        if !has_custom_icon {
+        if custom_icon.is_none() {
             let (target, _) = WindowsApi::resolve_lnk_target(origin)?;
             _extract_and_save_icon_from_file(&target)?;
             RESOURCES.add_system_icon_redirect(umid, origin, &target);
@@ -488,27 +689,24 @@ fn _extract_and_save_icon_from_file(origin: &Path) -> Result<()> {
         }
     }
 
-    // try get the icon directly from the file
-    let icon = get_shell_icon(origin)?;
-    gen_icon.is_aproximately_square = is_aproximately_a_square(&icon);
+    // the custom icon location of the shortcut is preferred as it is read at full resolution,
+    // otherwise try get the icon directly from the file
+    let icon = match custom_icon {
+        Some((icon_path, index)) => extract_icon_from_module(&icon_path, index, JUMBO_ICON_SIZE)
+            .map(|image| crop_transparent_borders(&image))
+            .or_else(|_| get_shell_icon(origin))?,
+        None => get_shell_icon(origin)?,
+    };
+    let gen_icon = Icon {
+        base: Some(save_on_system_icon_pack(&icon)?),
+        is_aproximately_square: is_aproximately_a_square(&icon),
+        dominant_color: get_dominant_color(&icon),
+        ..Default::default()
+    };
 
     if is_exe_file || is_lnk_file {
-        save_as_webp(
-            &icon,
-            &SEELEN_COMMON
-                .system_icon_pack_path()
-                .join(&gen_icon_filename),
-        )?;
         RESOURCES.add_system_app_icon(umid.as_deref(), Some(origin), gen_icon);
     } else {
-        let gen_icon_filename = format!("{}_{}.webp", origin_ext, date_based_hex_id());
-        save_as_webp(
-            &icon,
-            &SEELEN_COMMON
-                .system_icon_pack_path()
-                .join(&gen_icon_filename),
-        )?;
-        gen_icon.base = Some(gen_icon_filename);
         RESOURCES.add_system_file_icon(&origin_ext, gen_icon);
     }
 
@@ -523,16 +721,16 @@ fn _extract_and_save_icon_umid(aumid: &AppUserModelId) -> Result<()> {
             let path = msix_manager.get_app_path(app_umid)?;
             {
                 if RESOURCES.has_app_icon(Some(aumid.as_str()), path.as_deref()) {
+                    Counter::IconCacheHit.increment();
                     return Ok(());
                 }
             }
+            Counter::IconCacheMiss.increment();
 
             log::trace!("Extracting icon for {app_umid:?}");
             let mut gen_icon = Icon::default();
             let (light_path, dark_path) = msix_manager.get_app_icon_path(app_umid)?;
 
-            let name = date_based_hex_id();
-
             let light_rgba = image::open(&light_path)?.to_rgba8();
             let light_rgba = crop_transparent_borders(&light_rgba);
 
@@ -540,32 +738,14 @@ fn _extract_and_save_icon_umid(aumid: &AppUserModelId) -> Result<()> {
                 let dark_rgba = image::open(&dark_path)?.to_rgba8();
                 let dark_rgba = crop_transparent_borders(&dark_rgba);
 
-                save_as_webp(
-                    &light_rgba,
-                    &SEELEN_COMMON
-                        .system_icon_pack_path()
-                        .join(format!("{name}_light.webp")),
-                )?;
-                save_as_webp(
-                    &dark_rgba,
-                    &SEELEN_COMMON
-                        .system_icon_pack_path()
-                        .join(format!("{name}_dark.webp")),
-                )?;
-
-                gen_icon.light = Some(format!("{name}_light.webp"));
-                gen_icon.dark = Some(format!("{name}_dark.webp"));
+                gen_icon.light = Some(save_on_system_icon_pack(&light_rgba)?);
+                gen_icon.dark = Some(save_on_system_icon_pack(&dark_rgba)?);
             } else {
-                save_as_webp(
-                    &light_rgba,
-                    &SEELEN_COMMON
-                        .system_icon_pack_path()
-                        .join(format!("{name}.webp")),
-                )?;
-                gen_icon.base = Some(format!("{name}.webp"));
+                gen_icon.base = Some(save_on_system_icon_pack(&light_rgba)?);
             }
 
             gen_icon.is_aproximately_square = is_aproximately_a_square(&light_rgba);
+            gen_icon.dominant_color = get_dominant_color(&light_rgba);
 
             RESOURCES.add_system_app_icon(Some(app_umid), path.as_deref(), gen_icon);
             Ok(())
@@ -578,10 +758,13 @@ fn _extract_and_save_icon_umid(aumid: &AppUserModelId) -> Result<()> {
 
             {
                 if RESOURCES.has_app_icon(Some(aumid.as_str()), Some(&lnk.path)) {
+                    Counter::IconCacheHit.increment();
                     return Ok(());
                 }
             }
 
+            // the hit or miss is counted by the extraction from the shortcut
+
             _extract_and_save_icon_from_file(&lnk.path)?;
             Ok(())
         }
@@ -637,9 +820,55 @@ pub fn is_aproximately_a_square(rgba_image: &RgbaImage) -> bool {
     true
 }
 
+/// pixels with lower alpha are ignored to avoid antialiasing/shadows to affect the result
+const DOMINANT_COLOR_MIN_ALPHA: u8 = 128;
+
+/// Returns the average color of the non transparent pixels as hex string.
+pub fn get_dominant_color(rgba_image: &RgbaImage) -> Option<String> {
+    let (mut r, mut g, mut b, mut count) = (0u64, 0u64, 0u64, 0u64);
+    for pixel in rgba_image.pixels() {
+        let [pr, pg, pb, pa] = pixel.0;
+        if pa < DOMINANT_COLOR_MIN_ALPHA {
+            continue;
+        }
+        r += pr as u64;
+        g += pg as u64;
+        b += pb as u64;
+        count += 1;
+    }
+
+    if count == 0 {
+        return None;
+    }
+
+    Some(format!(
+        "#{:02x}{:02x}{:02x}",
+        r / count,
+        g / count,
+        b / count
+    ))
+}
+
 fn save_as_webp(image: &RgbaImage, path: &std::path::Path) -> Result<()> {
     let webp_bytes =
         webp::Encoder::from_rgba(image.as_raw(), image.width(), image.height()).encode_lossless();
     std::fs::write(path, &*webp_bytes)?;
     Ok(())
 }
+
+/// Saves the icon on the system icon pack folder named by the hash of its content, so apps
+/// sharing the same icon (common on apps from the same vendor) also share the same file.
+///
+/// Returns the filename relative to the system icon pack folder.
+fn save_on_system_icon_pack(image: &RgbaImage) -> Result<String> {
+    let webp_bytes =
+        webp::Encoder::from_rgba(image.as_raw(), image.width(), image.height()).encode_lossless();
+    let hash = slu_utils::checksums::calculate_sha256(&webp_bytes);
+    let filename = format!("{}.webp", &hash[..32]);
+
+    let path = SEELEN_COMMON.system_icon_pack_path().join(&filename);
+    if !path.exists() {
+        std::fs::write(path, &*webp_bytes)?;
+    }
+    Ok(filename)
+}
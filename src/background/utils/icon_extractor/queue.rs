@@ -1,6 +1,7 @@
 use std::{
     path::{Path, PathBuf},
     sync::LazyLock,
+    time::SystemTime,
 };
 
 use serde::{Deserialize, Serialize};
@@ -10,7 +11,10 @@ use crate::{
     error::{Result, ResultLogExt},
     event_manager,
     modules::start::application::{StartMenuEvent, StartMenuManager},
-    utils::{constants::SEELEN_COMMON, lock_free::SyncVec},
+    utils::{
+        constants::SEELEN_COMMON,
+        lock_free::{SyncHashMap, SyncVec},
+    },
     windows_api::types::AppUserModelId,
 };
 
@@ -33,9 +37,20 @@ fn real_path_for_ext(path: &Path) -> std::borrow::Cow<'_, std::path::Path> {
     std::borrow::Cow::Borrowed(path)
 }
 
+/// Canonicalizes the path and reads its last modification time, used as the
+/// in-memory cache key/value for already extracted paths.
+fn cache_entry_for(path: &Path) -> Option<(PathBuf, SystemTime)> {
+    let canonical = path.canonicalize().ok()?;
+    let modified = std::fs::metadata(&canonical).ok()?.modified().ok()?;
+    Some((canonical, modified))
+}
+
 pub struct IconExtractor {
     failures: SyncVec<IconExtractorRequest>,
     save_failures: Debounce<()>,
+    /// Paths already extracted in this session (canonical path -> mtime at extraction time).
+    /// Avoids re-scanning the icon pack for every window of the same executable.
+    extracted: SyncHashMap<PathBuf, SystemTime>,
 }
 
 /// Represents a request to extract an icon.
@@ -66,6 +81,7 @@ impl IconExtractor {
     fn create() -> Self {
         let mut extractor = Self {
             failures: SyncVec::new(),
+            extracted: SyncHashMap::new(),
             save_failures: debounce(
                 |_| {
                     let path = SEELEN_COMMON.app_cache_dir().join("icon_failures2.yml");
@@ -82,16 +98,19 @@ impl IconExtractor {
 
         Self::subscribe(|task| {
             let m = Self::instance();
-            if m.is_failed(&task.request) {
+            if m.is_failed(&task.request) || m.is_already_extracted(&task.request) {
                 return;
             }
 
-            if let Err(err) = Self::process(&task.request) {
-                if !task.revalidating {
-                    log::error!("Failed to extract icon: {err}");
+            match Self::process(&task.request) {
+                Ok(()) => m.record_extracted(&task.request),
+                Err(err) => {
+                    if !task.revalidating {
+                        log::error!("Failed to extract icon: {err}");
+                    }
+                    m.record_failure(task.request);
+                    m.save_failures.call(());
                 }
-                m.record_failure(task.request);
-                m.save_failures.call(());
             }
         });
 
@@ -119,6 +138,8 @@ impl IconExtractor {
         &ICON_EXTRACTOR
     }
 
+    /// Only enqueues the request, the cache is checked by the queue worker since it touches
+    /// the filesystem and this can be called from the win event hook thread.
     pub fn request(&self, request: IconExtractorRequest) {
         Self::send(IconExtractorTask {
             request,
@@ -126,6 +147,33 @@ impl IconExtractor {
         });
     }
 
+    /// Returns true if the path was already extracted and the file was not modified since.
+    /// If the file was modified the cached entry is dropped so the icon is extracted again.
+    fn is_already_extracted(&self, request: &IconExtractorRequest) -> bool {
+        let IconExtractorRequest::Path(path) = request else {
+            return false;
+        };
+        let Some((canonical, modified)) = cache_entry_for(path) else {
+            return false;
+        };
+        match self.extracted.get(&canonical, |cached| *cached) {
+            Some(cached) if cached == modified => true,
+            Some(_) => {
+                self.extracted.remove(&canonical);
+                false
+            }
+            None => false,
+        }
+    }
+
+    fn record_extracted(&self, request: &IconExtractorRequest) {
+        if let IconExtractorRequest::Path(path) = request {
+            if let Some((canonical, modified)) = cache_entry_for(path) {
+                self.extracted.upsert(canonical, modified);
+            }
+        }
+    }
+
     /// Returns true if this request is already known to fail and should be skipped.
     fn is_failed(&self, request: &IconExtractorRequest) -> bool {
         if self.failures.contains(request) {
@@ -176,6 +224,20 @@ impl IconExtractor {
         self.failures.push(request);
     }
 
+    /// Forgets any cached result for this path and queues it to be extracted again.
+    pub fn refresh(&self, path: &Path) {
+        if let Some((canonical, _)) = cache_entry_for(path) {
+            self.extracted.remove(&canonical);
+        }
+        let request = IconExtractorRequest::Path(path.to_path_buf());
+        self.failures.retain(|f| f != &request);
+        self.request(request);
+    }
+
+    pub fn clear_extracted(&self) {
+        self.extracted.clear();
+    }
+
     pub fn clear_failures(&self) {
         self.failures.clear();
         let path = SEELEN_COMMON.app_cache_dir().join("icon_failures2.yml");
@@ -1,4 +1,5 @@
 pub mod constants;
+pub mod diagnostics;
 pub mod discord;
 pub mod icon_extractor;
 pub mod integrity;
@@ -195,6 +196,55 @@ pub fn get_parts_of_inline_command(cmd: &str) -> (String, Option<String>) {
     (program, if args.is_empty() { None } else { Some(args) })
 }
 
+/// Removes the trailing app name commonly added by apps to their titles,
+/// ex: `Document - Word` -> `Document` or `Page — Mozilla Firefox` -> `Page`.
+pub fn short_title(title: &str, app_name: &str) -> String {
+    if app_name.is_empty() {
+        return title.to_owned();
+    }
+    let short = title.strip_suffix(app_name).and_then(|prefix| {
+        [" - ", " — ", " | "]
+            .iter()
+            .find_map(|separator| prefix.strip_suffix(separator))
+    });
+    match short {
+        Some(short) if !short.trim().is_empty() => short.trim().to_owned(),
+        _ => title.to_owned(),
+    }
+}
+
+/// Quotes an argument following the rules used by `CommandLineToArgvW` and the msvc runtime,
+/// so it is received as a single argument even if it contains spaces, quotes or backslashes.
+pub fn quote_command_line_arg(arg: &str) -> String {
+    if !arg.is_empty() && !arg.contains([' ', '\t', '\n', '"']) {
+        return arg.to_owned();
+    }
+
+    let mut quoted = String::with_capacity(arg.len() + 2);
+    quoted.push('"');
+    let mut backslashes = 0;
+    for c in arg.chars() {
+        match c {
+            '\\' => backslashes += 1,
+            '"' => {
+                // backslashes before a quote are escaped, and the quote itself too
+                quoted.extend(std::iter::repeat_n('\\', backslashes * 2 + 1));
+                quoted.push('"');
+                backslashes = 0;
+            }
+            _ => {
+                quoted.extend(std::iter::repeat_n('\\', backslashes));
+                quoted.push(c);
+                backslashes = 0;
+            }
+        }
+    }
+    // backslashes before the closing quote must be escaped
+    quoted.extend(std::iter::repeat_n('\\', backslashes * 2));
+    quoted.push('"');
+    quoted
+}
+
 pub static CRONOMETER: LazyLock<Cronometer> = LazyLock::new(|| Cronometer(Instant::now()));
 
 pub struct Cronometer(pub Instant);
@@ -208,3 +258,52 @@ impl Cronometer {
         log::debug!("{} at: {:?}", name, self.elapsed());
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn short_title_strips_the_trailing_app_name() {
+        assert_eq!(short_title("Document - Word", "Word"), "Document");
+        assert_eq!(
+            short_title("Page — Mozilla Firefox", "Mozilla Firefox"),
+            "Page"
+        );
+        assert_eq!(short_title("Chat | Discord", "Discord"), "Chat");
+    }
+
+    #[test]
+    fn short_title_keeps_titles_without_a_separated_app_name() {
+        assert_eq!(short_title("Word", "Word"), "Word");
+        assert_eq!(short_title("MyWord", "Word"), "MyWord");
+        assert_eq!(short_title("Word - Document", "Word"), "Word - Document");
+        assert_eq!(short_title("Document - Word", ""), "Document - Word");
+    }
+
+    #[test]
+    fn short_title_keeps_the_title_if_nothing_remains() {
+        assert_eq!(short_title("  - Word", "Word"), "  - Word");
+    }
+
+    #[test]
+    fn quote_command_line_arg_keeps_simple_args() {
+        assert_eq!(quote_command_line_arg(r"C:\file.txt"), r"C:\file.txt");
+    }
+
+    #[test]
+    fn quote_command_line_arg_quotes_spaces_and_empty() {
+        assert_eq!(
+            quote_command_line_arg(r"C:\my file.txt"),
+            r#""C:\my file.txt""#
+        );
+        assert_eq!(quote_command_line_arg(""), r#""""#);
+    }
+
+    #[test]
+    fn quote_command_line_arg_escapes_quotes_and_trailing_backslashes() {
+        assert_eq!(quote_command_line_arg(r#"say "hi""#), r#""say \"hi\"""#);
+        assert_eq!(quote_command_line_arg(r"C:\my dir\"), r#""C:\my dir\\""#);
+        assert_eq!(quote_command_line_arg(r#"a\"b"#), r#""a\\\"b""#);
+    }
+}
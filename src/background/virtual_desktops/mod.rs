@@ -178,6 +178,17 @@ impl SluWorkspacesManager2 {
             _ => {}
         });
 
+        Self::subscribe(|event| {
+            if matches!(
+                event,
+                VirtualDesktopEvent::DesktopChanged { .. }
+                    | VirtualDesktopEvent::WindowAdded { .. }
+                    | VirtualDesktopEvent::WindowMoved { .. }
+            ) {
+                Self::instance().sync_user_apps_current_desktop();
+            }
+        });
+
         let eid = HookManager::subscribe(|(event, origin)| {
             Self::on_win_event(event, origin).log_error();
         });
@@ -332,6 +343,48 @@ impl SluWorkspacesManager2 {
         }
     }
 
+    /// Pinned windows and windows not bound to any workspace are considered on the current one.
+    fn is_on_current_workspace(&self, window_id: &isize) -> bool {
+        if self.is_pinned(window_id) {
+            return true;
+        }
+        let mut on_current = true;
+        self.monitors.for_each(|(_, monitor)| {
+            if let Some(workspace) = monitor
+                .workspaces
+                .iter()
+                .find(|w| w.windows.contains(window_id))
+            {
+                on_current = &workspace.id == monitor.active_workspace_id();
+            }
+        });
+        on_current
+    }
+
+    /// Updates the `on_current_desktop` flag of the user app windows.
+    fn sync_user_apps_current_desktop(&self) {
+        let apps = UserAppsManager::instance();
+        let states: HashMap<isize, bool> = apps
+            .interactable_windows
+            .map(|w| w.hwnd)
+            .into_iter()
+            .map(|hwnd| (hwnd, self.is_on_current_workspace(&hwnd)))
+            .collect();
+
+        let mut changed = Vec::new();
+        apps.interactable_windows.for_each(|w| {
+            if let Some(on_current) = states.get(&w.hwnd) {
+                if w.on_current_desktop != *on_current {
+                    w.on_current_desktop = *on_current;
+                    changed.push(w.hwnd);
+                }
+            }
+        });
+        for hwnd in changed {
+            UserAppsManager::send(UserAppWinEvent::Updated(hwnd));
+        }
+    }
+
     fn add_to_current_workspace(&self, window: &Window) {
         let window_id = window.address();
 
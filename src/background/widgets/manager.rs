@@ -14,7 +14,7 @@ use crate::{
     resources::RESOURCES,
     state::application::FULL_STATE,
     utils::lock_free::SyncHashMap,
-    widgets::{loader::WidgetDeployment, WidgetWebviewLabel},
+    widgets::{loader::WidgetDeployment, weg::SeelenWeg, WidgetWebviewLabel},
 };
 
 pub static WIDGET_MANAGER: LazyLock<WidgetManager> = LazyLock::new(WidgetManager::create);
@@ -57,6 +57,7 @@ impl WidgetManager {
         self.deployments.for_each(|(_, deploy)| {
             deploy.pods.clear();
         });
+        SeelenWeg::forget_removed_instances();
     }
 
     pub fn resume_all(&self) -> Result<()> {
@@ -113,6 +114,7 @@ impl WidgetManager {
             WIDGET_MANAGER.deployments.for_each(|(_, deployment)| {
                 reconcile(deployment);
             });
+            SeelenWeg::forget_removed_instances();
         });
 
         Ok(())
@@ -8,12 +8,14 @@ use seelen_core::{
 };
 
 use tauri::Manager;
+use windows::Win32::Foundation::HWND;
 
 use crate::{
     app::get_app_handle,
     error::{Result, ResultLogExt},
     state::application::FULL_STATE,
     utils::constants::SEELEN_COMMON,
+    windows_api::AppBarData,
 };
 
 pub struct WidgetWebview(pub tauri::WebviewWindow);
@@ -115,6 +117,16 @@ impl Drop for WidgetWebview {
         // manager (get_webview_window returns None), so calling destroy() again
         // would cause re-entrant ZwUserDestroyWindow → FATAL_USER_CALLBACK_EXCEPTION.
         let label = self.0.label().to_owned();
+
+        // widgets can be dropped without unregistering their app bar (ex: monitor disconnected),
+        // so the reserved work area is released here.
+        if let Ok(hwnd) = self.0.hwnd() {
+            let mut app_bar = AppBarData::from_handle(HWND(hwnd.0));
+            if app_bar.is_registered() {
+                app_bar.unregister_bar().log_error();
+            }
+        }
+
         if get_app_handle().get_webview_window(&label).is_some() {
             let _ = self.0.destroy();
         }
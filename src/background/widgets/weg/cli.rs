@@ -1,16 +1,21 @@
+use std::path::{Path, PathBuf};
+
 pub use slu_ipc::commands::WegCli;
 use slu_ipc::commands::WegCommand;
 
 use seelen_core::{
+    handlers::SeelenEvent,
     state::{WegItem, WegItemData},
-    system_state::UserAppWindow,
+    system_state::{MonitorId, Relaunch, UserAppWindow},
 };
 use windows::Win32::UI::WindowsAndMessaging::SW_MINIMIZE;
 
 use crate::{
+    app::emit_to_webviews,
     error::Result,
     modules::apps::application::USER_APPS_MANAGER,
-    state::application::WEG_ITEMS_MANAGER,
+    state::application::{FULL_STATE, WEG_ITEMS_MANAGER},
+    widgets::weg::{handler::weg_pin_item, SeelenWeg},
     windows_api::{window::Window, WindowsApi},
 };
 
@@ -52,52 +57,83 @@ fn get_windows_for_item<'a>(
         .collect()
 }
 
+/// Mirrors `getLaunchOverride` from the frontend (`appMenu.ts`).
+fn get_launch_override(item: &WegItemData) -> Option<Relaunch> {
+    let state = FULL_STATE.load();
+    let path = item.path.to_string_lossy();
+    state
+        .settings
+        .by_widget
+        .weg
+        .launch_overrides
+        .iter()
+        .find(|(key, _)| item.umid.as_ref() == Some(*key) || key.eq_ignore_ascii_case(&path))
+        .map(|(_, relaunch)| relaunch.clone())
+}
+
 pub fn process(cmd: WegCli) -> Result<()> {
-    #[allow(irrefutable_let_patterns)]
-    if let WegCommand::ForegroundOrRunApp { index } = cmd.subcommand {
-        let weg_items = WEG_ITEMS_MANAGER.get();
+    match cmd.subcommand {
+        WegCommand::ForegroundOrRunApp { index } => foreground_or_run_app(index),
+        WegCommand::ToggleVisibility { monitor } => toggle_visibility(monitor.map(MonitorId::from)),
+        WegCommand::Pin { path } => pin(&path),
+        WegCommand::Unpin { path } => unpin(&path),
+        WegCommand::Focus { exe } => focus(&exe),
+    }
+}
 
-        let all_items: Vec<&WegItem> = weg_items
-            .left
-            .iter()
-            .chain(weg_items.center.iter())
-            .chain(weg_items.right.iter())
-            .filter(|item| matches!(item, WegItem::AppOrFile(_)))
-            .collect();
+fn foreground_or_run_app(index: usize) -> Result<()> {
+    let weg_items = WEG_ITEMS_MANAGER.get();
 
-        if all_items.len() <= index {
-            return Ok(());
-        }
+    let all_items: Vec<&WegItem> = weg_items
+        .left
+        .iter()
+        .chain(weg_items.center.iter())
+        .chain(weg_items.right.iter())
+        .filter(|item| matches!(item, WegItem::AppOrFile(_)))
+        .collect();
 
-        let WegItem::AppOrFile(inner_data) = all_items[index] else {
-            return Ok(());
-        };
+    if all_items.len() <= index {
+        return Ok(());
+    }
 
-        let interactables = USER_APPS_MANAGER.interactable_windows.to_vec();
-        let windows = get_windows_for_item(inner_data, &interactables);
+    let WegItem::AppOrFile(inner_data) = all_items[index] else {
+        return Ok(());
+    };
 
-        if windows.is_empty() {
-            let command = inner_data
-                .relaunch
-                .as_ref()
-                .map(|r| r.command.clone())
-                .unwrap_or_else(|| inner_data.path.to_string_lossy().to_string());
-            let args = inner_data
-                .relaunch
-                .as_ref()
-                .and_then(|r| r.args.as_ref())
-                .map(|a| a.to_string());
-            let working_dir = inner_data
-                .relaunch
-                .as_ref()
-                .and_then(|r| r.working_dir.clone());
-            WindowsApi::execute(command, args, working_dir, false)?;
-        } else {
-            let focused = windows.iter().find(|w| Window::from(w.hwnd).is_focused());
-            if let Some(w) = focused {
-                Window::from(w.hwnd).show_window_async(SW_MINIMIZE)?;
-            } else if let Some(w) = windows.first() {
-                let window = Window::from(w.hwnd);
+    let interactables = USER_APPS_MANAGER.interactable_windows.to_vec();
+    let windows = get_windows_for_item(inner_data, &interactables);
+
+    if windows.is_empty() {
+        let relaunch = get_launch_override(inner_data).or_else(|| inner_data.relaunch.clone());
+        let command = relaunch
+            .as_ref()
+            .map(|r| r.command.clone())
+            .unwrap_or_else(|| inner_data.path.to_string_lossy().to_string());
+        let args = relaunch
+            .as_ref()
+            .and_then(|r| r.args.as_ref())
+            .map(|a| a.to_string());
+        let working_dir = relaunch.as_ref().and_then(|r| r.working_dir.clone());
+        WindowsApi::execute(command, args, working_dir, false)?;
+    } else {
+        let focused_idx = windows
+            .iter()
+            .position(|w| Window::from(w.hwnd).is_focused());
+        match focused_idx {
+            // single window focused, toggle it as the native taskbar does
+            Some(idx) if windows.len() == 1 => {
+                Window::from(windows[idx].hwnd).show_window_async(SW_MINIMIZE)?;
+            }
+            // multiple windows, repeated presses cycle through them
+            Some(idx) => {
+                let next = Window::from(windows[(idx + 1) % windows.len()].hwnd);
+                if next.is_window() {
+                    next.unminimize()?;
+                    next.focus()?;
+                }
+            }
+            None => {
+                let window = Window::from(windows[0].hwnd);
                 if window.is_window() {
                     window.unminimize()?;
                     window.focus()?;
@@ -107,3 +143,112 @@ pub fn process(cmd: WegCli) -> Result<()> {
     }
     Ok(())
 }
+
+fn toggle_visibility(monitor: Option<MonitorId>) -> Result<()> {
+    if !SeelenWeg::has_instance(monitor.as_ref()) {
+        return Err(match &monitor {
+            Some(id) => format!("There is no dock on monitor {id}").into(),
+            None => "There is no dock running".into(),
+        });
+    }
+
+    emit_to_webviews(SeelenEvent::WegToggleVisibility, monitor);
+    Ok(())
+}
+
+fn pinned_items() -> Vec<WegItemData> {
+    let weg_items = WEG_ITEMS_MANAGER.get();
+    weg_items
+        .left
+        .into_iter()
+        .chain(weg_items.center)
+        .chain(weg_items.right)
+        .filter_map(|item| match item {
+            WegItem::AppOrFile(data) if data.pinned => Some(data),
+            _ => None,
+        })
+        .collect()
+}
+
+fn same_path(a: &Path, b: &Path) -> bool {
+    a.to_string_lossy()
+        .eq_ignore_ascii_case(&b.to_string_lossy())
+}
+
+/// Accepts a full path or a bare exe name, the last one is resolved against the given candidates.
+fn resolve_app_path(value: &str, candidates: impl IntoIterator<Item = PathBuf>) -> Result<PathBuf> {
+    let path = PathBuf::from(value);
+    if path.is_absolute() {
+        return Ok(path);
+    }
+
+    candidates
+        .into_iter()
+        .find(|candidate| {
+            candidate
+                .file_name()
+                .is_some_and(|name| name.to_string_lossy().eq_ignore_ascii_case(value))
+        })
+        .ok_or_else(|| format!("No application found for {value}").into())
+}
+
+fn open_apps_paths() -> Vec<PathBuf> {
+    USER_APPS_MANAGER
+        .interactable_windows
+        .map(|w| w.process.path.clone())
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+fn pin(value: &str) -> Result<()> {
+    let path = resolve_app_path(value, open_apps_paths())?;
+    if pinned_items()
+        .iter()
+        .any(|item| same_path(&item.path, &path))
+    {
+        return Ok(());
+    }
+    weg_pin_item(path)
+}
+
+fn unpin(value: &str) -> Result<()> {
+    let pinned = pinned_items();
+    let candidates = pinned
+        .iter()
+        .map(|item| item.path.clone())
+        .chain(open_apps_paths());
+    let path = resolve_app_path(value, candidates)?;
+
+    if !pinned.iter().any(|item| same_path(&item.path, &path)) {
+        return Err(format!("{} is not pinned", path.display()).into());
+    }
+    emit_to_webviews(SeelenEvent::WegRemovePinnedItem, path);
+    Ok(())
+}
+
+fn focus(exe: &str) -> Result<()> {
+    let by_path = Path::new(exe).is_absolute();
+    let target = USER_APPS_MANAGER
+        .interactable_windows
+        .to_vec()
+        .into_iter()
+        .filter(|w| {
+            w.process.path.as_ref().is_some_and(|path| {
+                if by_path {
+                    same_path(path, Path::new(exe))
+                } else {
+                    path.file_name()
+                        .is_some_and(|name| name.to_string_lossy().eq_ignore_ascii_case(exe))
+                }
+            })
+        })
+        .max_by_key(|w| w.last_foreground_at)
+        .ok_or_else(|| format!("No open window found for {exe}"))?;
+
+    let window = Window::from(target.hwnd);
+    window.unminimize()?;
+    window.focus()?;
+    log::info!("Focused {window}");
+    Ok(())
+}
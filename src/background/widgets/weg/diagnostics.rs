@@ -0,0 +1,28 @@
+use seelen_core::state::WegDiagnostics;
+
+use crate::{
+    modules::apps::application::UserAppWinEvent,
+    utils::diagnostics::{self, Counter},
+};
+
+impl From<UserAppWinEvent> for Counter {
+    fn from(event: UserAppWinEvent) -> Self {
+        match event {
+            UserAppWinEvent::Added(_) => Counter::WindowAdded,
+            UserAppWinEvent::Removed(_) => Counter::WindowRemoved,
+            UserAppWinEvent::Updated(_) => Counter::WindowUpdated,
+        }
+    }
+}
+
+pub fn get_diagnostics() -> WegDiagnostics {
+    WegDiagnostics {
+        enabled: diagnostics::is_enabled(),
+        windows_added: Counter::WindowAdded.get(),
+        windows_removed: Counter::WindowRemoved.get(),
+        windows_updated: Counter::WindowUpdated.get(),
+        icon_cache_hits: Counter::IconCacheHit.get(),
+        icon_cache_misses: Counter::IconCacheMiss.get(),
+        overlap_toggles: Counter::OverlapToggled.get(),
+    }
+}
@@ -1,18 +1,54 @@
-use std::path::PathBuf;
+use std::{
+    path::PathBuf,
+    sync::{LazyLock, Once},
+};
 
-use seelen_core::{handlers::SeelenEvent, state::WegItemData};
+use parking_lot::Mutex;
+use seelen_core::{
+    handlers::SeelenEvent,
+    rect::Rect,
+    state::{WegDiagnostics, WegInstanceState, WegItemData, WegMonitorInfo},
+    system_state::MonitorId,
+};
 use tauri_plugin_shell::ShellExt;
 
 use crate::{
     app::{emit_to_webviews, get_app_handle},
-    error::Result,
-    windows_api::{window::Window, WindowsApi},
+    error::{Result, ResultLogExt},
+    modules::{apps::application::USER_APPS_MANAGER, monitors::MonitorManager},
+    utils::{diagnostics::Counter, lock_free::SyncHashMap, quote_command_line_arg},
+    widgets::{
+        permissions::{request_widget_permission, WidgetPerm},
+        webview::WidgetWebviewLabel,
+        weg::{diagnostics::get_diagnostics, SeelenWeg},
+    },
+    windows_api::{
+        event_window::subscribe_to_background_window, monitor::Monitor, window::Window,
+        MonitorEnumerator, WindowsApi,
+    },
+};
+use windows::Win32::UI::WindowsAndMessaging::{
+    SPI_SETWORKAREA, SW_MINIMIZE, SW_RESTORE, SW_SHOWMINNOACTIVE, WM_CLOSE, WM_SETTINGCHANGE,
 };
-use windows::Win32::UI::WindowsAndMessaging::{SW_MINIMIZE, WM_CLOSE};
+
+/// Windows minimized by the current desktop peek, most recently focused first.
+static PEEKED_WINDOWS: LazyLock<Mutex<Vec<isize>>> = LazyLock::new(|| Mutex::new(Vec::new()));
+
+/// Placement of each dock instance, reported by the docks on every change.
+static INSTANCES_STATE: LazyLock<SyncHashMap<MonitorId, WegInstanceState>> =
+    LazyLock::new(SyncHashMap::new);
+
+/// Last monitor metadata sent to each dock instance.
+static MONITORS_INFO: LazyLock<SyncHashMap<MonitorId, WegMonitorInfo>> =
+    LazyLock::new(SyncHashMap::new);
 
 #[tauri::command(async)]
 pub fn weg_close_app(hwnd: isize) -> Result<()> {
     let window = Window::from(hwnd);
+    if !window.is_window() {
+        return Err("Window not found".into());
+    }
+    // only request a graceful close, the app can still ask the user to save changes
     WindowsApi::post_message(window.hwnd(), WM_CLOSE, 0, 0)?;
     Ok(())
 }
@@ -42,6 +78,37 @@ pub fn weg_toggle_window_state(hwnd: isize, was_focused: bool) -> Result<()> {
     Ok(())
 }
 
+/// Focuses the next (direction > 0) or previous (direction < 0) window of the group,
+/// relative to the currently focused one, minimized windows are restored.
+#[tauri::command(async)]
+pub fn weg_cycle_app_windows(hwnds: Vec<isize>, direction: i32) -> Result<()> {
+    let windows: Vec<Window> = hwnds
+        .into_iter()
+        .map(Window::from)
+        .filter(|w| w.is_window())
+        .collect();
+    if windows.is_empty() {
+        return Ok(());
+    }
+
+    let len = windows.len() as i32;
+    let next_idx = match windows.iter().position(|w| w.is_focused()) {
+        Some(idx) => (idx as i32 + direction.signum()).rem_euclid(len),
+        None if direction < 0 => len - 1,
+        None => 0,
+    };
+
+    let window = &windows[next_idx as usize];
+    window.unminimize()?;
+    window.focus()?;
+    Ok(())
+}
+
+#[tauri::command(async)]
+pub fn weg_get_native_taskbar_rect(monitor_id: MonitorId) -> Option<Rect> {
+    SeelenWeg::native_taskbar_rect(&monitor_id)
+}
+
 #[tauri::command(async)]
 pub fn weg_pin_item(path: PathBuf) -> Result<()> {
     if !path.exists() || path.is_dir() {
@@ -68,3 +135,183 @@ pub fn weg_pin_item(path: PathBuf) -> Result<()> {
     emit_to_webviews(SeelenEvent::WegAddItem, &item);
     Ok(())
 }
+
+/// Launches the program passing the dropped files as arguments
+#[tauri::command(async)]
+pub fn weg_open_files_with(
+    webview: tauri::WebviewWindow,
+    program: PathBuf,
+    paths: Vec<PathBuf>,
+) -> Result<()> {
+    request_widget_permission(&webview, WidgetPerm::Run)?;
+    if !program.is_file() {
+        return Err("Invalid program path".into());
+    }
+    let args = paths
+        .iter()
+        .map(|p| quote_command_line_arg(&p.to_string_lossy()))
+        .collect::<Vec<_>>()
+        .join(" ");
+    WindowsApi::execute(
+        program.to_string_lossy().to_string(),
+        Some(args),
+        None,
+        false,
+    )
+}
+
+/// Minimizes the windows of the monitor until `weg_stop_peek_desktop` is called,
+/// intended for hold-to-peek gestures like the "Show desktop" corner of the native taskbar.
+#[tauri::command(async)]
+pub fn weg_peek_desktop(monitor_id: MonitorId) -> Result<()> {
+    let mut peeked = PEEKED_WINDOWS.lock();
+    if !peeked.is_empty() {
+        return Ok(());
+    }
+
+    let mut windows = USER_APPS_MANAGER.interactable_windows.to_vec();
+    windows.retain(|w| w.monitor == monitor_id);
+    windows.sort_by_key(|w| std::cmp::Reverse(w.last_foreground_at));
+
+    for app in windows {
+        let window = Window::from(app.hwnd);
+        if window.is_window() && !window.is_minimized() {
+            window.show_window_async(SW_SHOWMINNOACTIVE).log_error();
+            peeked.push(app.hwnd);
+        }
+    }
+    Ok(())
+}
+
+/// Restores only the windows minimized by `weg_peek_desktop`, keeping their previous order.
+#[tauri::command(async)]
+pub fn weg_stop_peek_desktop() -> Result<()> {
+    let peeked = std::mem::take(&mut *PEEKED_WINDOWS.lock());
+    for addr in peeked.iter().rev() {
+        let window = Window::from(*addr);
+        // the user could have restored it already while peeking
+        if window.is_window() && window.is_minimized() {
+            // sync show to restore them in order, bottom first
+            window.show_window(SW_RESTORE).log_error();
+        }
+    }
+
+    if let Some(addr) = peeked.first() {
+        Window::from(*addr).focus().log_error();
+    }
+    Ok(())
+}
+
+#[tauri::command(async)]
+pub fn weg_report_instance_state(
+    webview: tauri::WebviewWindow,
+    mut state: WegInstanceState,
+) -> Result<()> {
+    let label = WidgetWebviewLabel::try_from_raw(webview.label())?;
+    state.monitor_id = label
+        .monitor_id
+        .ok_or("The dock is not bound to a monitor")?;
+    let last = INSTANCES_STATE.get(&state.monitor_id, |last| last.clone());
+    if last.as_ref() == Some(&state) {
+        return Ok(());
+    }
+    if last.is_some_and(|last| last.is_overlapped != state.is_overlapped) {
+        Counter::OverlapToggled.increment();
+    }
+    INSTANCES_STATE.upsert(state.monitor_id.clone(), state.clone());
+    emit_to_webviews(SeelenEvent::WegInstanceStateChanged, &state);
+    Ok(())
+}
+
+#[tauri::command(async)]
+pub fn get_weg_diagnostics() -> WegDiagnostics {
+    get_diagnostics()
+}
+
+#[tauri::command(async)]
+pub fn weg_get_rect(monitor_id: MonitorId) -> Result<WegInstanceState> {
+    if !SeelenWeg::has_instance(Some(&monitor_id)) {
+        INSTANCES_STATE.remove(&monitor_id);
+        return Err(format!("There is no dock on monitor {monitor_id}").into());
+    }
+    INSTANCES_STATE
+        .get(&monitor_id, |state| state.clone())
+        .ok_or_else(|| format!("The dock on monitor {monitor_id} is not ready yet").into())
+}
+
+/// Forces the whole dock to capture (or let through) the mouse input, for overlays that need
+/// the input without depending on the hit testing of the dock.
+#[tauri::command(async)]
+pub fn weg_set_interactive(monitor_id: MonitorId, interactive: bool) -> Result<()> {
+    SeelenWeg::set_interactive(&monitor_id, interactive)
+}
+
+/// Regions in physical pixels that capture the input while hovered, an empty list clears them.
+/// They only apply once the input of the dock is owned by the backend via `weg_set_interactive`.
+#[tauri::command(async)]
+pub fn weg_set_interactive_regions(monitor_id: MonitorId, regions: Vec<Rect>) -> Result<()> {
+    SeelenWeg::set_interactive_regions(&monitor_id, regions)
+}
+
+fn get_monitor_info(monitor: &Monitor) -> Result<WegMonitorInfo> {
+    Ok(WegMonitorInfo {
+        monitor_id: monitor.stable_id()?,
+        device_name: monitor.device_name()?,
+        scale_factor: monitor.scale_factor()?,
+        work_area: monitor.work_area()?,
+    })
+}
+
+/// Emits the monitor metadata of the docks whose monitor changed since the last emission.
+fn emit_monitors_info_changes() -> Result<()> {
+    for monitor in MonitorEnumerator::enumerate_win32()? {
+        let Ok(info) = get_monitor_info(&monitor) else {
+            continue;
+        };
+        if !SeelenWeg::has_instance(Some(&info.monitor_id)) {
+            MONITORS_INFO.remove(&info.monitor_id);
+            continue;
+        }
+        let changed = MONITORS_INFO
+            .get(&info.monitor_id, |last| *last != info)
+            .unwrap_or(true);
+        if changed {
+            MONITORS_INFO.upsert(info.monitor_id.clone(), info.clone());
+            emit_to_webviews(SeelenEvent::WegMonitorInfoChanged, &info);
+        }
+    }
+    Ok(())
+}
+
+fn init_monitors_info_events() {
+    static INIT: Once = Once::new();
+    INIT.call_once(|| {
+        MonitorManager::subscribe(|_| emit_monitors_info_changes().log_error());
+        // app bars (native taskbar, toolbar, etc) registering or moving change the work area
+        subscribe_to_background_window(|msg, w_param, _| {
+            if msg == WM_SETTINGCHANGE && w_param as u32 == SPI_SETWORKAREA.0 {
+                emit_monitors_info_changes()?;
+            }
+            Ok(())
+        });
+    });
+}
+
+/// Monitor metadata of the caller dock, further changes are emitted as `WegMonitorInfoChanged`.
+#[tauri::command(async)]
+pub fn weg_get_monitor_info(webview: tauri::WebviewWindow) -> Result<WegMonitorInfo> {
+    init_monitors_info_events();
+    let label = WidgetWebviewLabel::try_from_raw(webview.label())?;
+    let monitor_id = label
+        .monitor_id
+        .ok_or("The dock is not bound to a monitor")?;
+
+    for monitor in MonitorEnumerator::enumerate_win32()? {
+        if monitor.stable_id().is_ok_and(|id| id == monitor_id) {
+            let info = get_monitor_info(&monitor)?;
+            MONITORS_INFO.upsert(monitor_id, info.clone());
+            return Ok(info);
+        }
+    }
+    Err(format!("Monitor {monitor_id} not found").into())
+}
@@ -34,6 +34,11 @@ impl SeelenWeg {
             EVENT_OBJECT_SHOW | EVENT_OBJECT_CREATE => {
                 let class = origin.class();
                 let parent_class = origin.parent().map(|p| p.class()).unwrap_or_default();
+                if TASKBAR_CLASS.contains(&class.as_str()) && event == EVENT_OBJECT_CREATE {
+                    Self::hide_new_native_taskbar();
+                    return Ok(());
+                }
+
                 if TASKBAR_CLASS
                     .iter()
                     .any(|t| t == &class || t == &parent_class)
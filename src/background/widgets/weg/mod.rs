@@ -1,15 +1,52 @@
 pub mod cli;
+pub mod diagnostics;
 pub mod handler;
 pub mod hook;
 
+use std::sync::LazyLock;
+
+use seelen_core::{
+    rect::{Point, Rect},
+    resource::WidgetId,
+    system_state::MonitorId,
+};
 use slu_ipc::messages::SvcAction;
+use tauri::Manager;
 
 use crate::{
-    cli::ServicePipe, error::Result, state::application::FULL_STATE, windows_api::monitor::Monitor,
+    app::get_app_handle,
+    cli::ServicePipe,
+    error::{Result, ResultLogExt},
+    state::application::FULL_STATE,
+    utils::lock_free::SyncHashMap,
+    widgets::manager::WIDGET_MANAGER,
+    windows_api::{monitor::Monitor, MonitorEnumerator, WindowEnumerator},
 };
 
 pub static TASKBAR_CLASS: [&str; 2] = ["Shell_TrayWnd", "Shell_SecondaryTrayWnd"];
 
+/// Input capture forced by the backend on each dock, see `SeelenWeg::set_interactive`.
+static INPUT_CAPTURE: LazyLock<SyncHashMap<MonitorId, InputCapture>> =
+    LazyLock::new(SyncHashMap::new);
+
+#[derive(Default)]
+struct InputCapture {
+    /// Last state requested via `set_interactive`, while None the dock does its own hit testing
+    /// and the backend never touches the input of the window.
+    requested: Option<bool>,
+    /// Screen regions (physical pixels) that capture the input while hovered, like preview
+    /// popups, even if the dock was requested to be click-through.
+    regions: Vec<Rect>,
+    is_hovered: bool,
+}
+
+impl InputCapture {
+    fn effective(&self) -> Option<bool> {
+        self.requested
+            .map(|interactive| interactive || self.is_hovered)
+    }
+}
+
 pub struct SeelenWeg {}
 
 impl SeelenWeg {
@@ -31,10 +68,154 @@ impl SeelenWeg {
     // requests the action via IPC.
 
     pub fn hide_native_taskbar() {
-        let _ = ServicePipe::request(SvcAction::HideNativeTaskbar);
+        Self::_hide_native_taskbar(false);
+    }
+
+    /// Should be used when a new native taskbar window could be created (ex: explorer restarted).
+    pub fn hide_new_native_taskbar() {
+        Self::_hide_native_taskbar(true);
+    }
+
+    fn _hide_native_taskbar(refresh: bool) {
+        let state = FULL_STATE.load();
+        let settings = &state.settings.by_widget.weg;
+        // the dock is placed over the native taskbar work area, so it is kept everywhere
+        if settings.coexist_with_taskbar {
+            let _ = ServicePipe::request(SvcAction::RestoreNativeTaskbar);
+            return;
+        }
+        let keep_on = &settings.keep_native_taskbar_on;
+
+        // the service doesn't know the stable monitor ids, so they are resolved to monitor
+        // handles here and matched against the cached taskbars by the service.
+        let mut keep_on_monitors = Vec::new();
+        if !keep_on.is_empty() {
+            for monitor in MonitorEnumerator::enumerate_win32().unwrap_or_default() {
+                if monitor.stable_id().is_ok_and(|id| keep_on.contains(&id)) {
+                    keep_on_monitors.push(monitor.handle().0 as isize);
+                }
+            }
+        }
+        let _ = ServicePipe::request(SvcAction::HideNativeTaskbar {
+            keep_on_monitors,
+            refresh,
+        });
     }
 
     pub fn restore_native_taskbar() -> Result<()> {
         ServicePipe::request(SvcAction::RestoreNativeTaskbar)
     }
+
+    /// Whether a dock instance is deployed on the monitor, or on any monitor if `None`.
+    pub fn has_instance(monitor_id: Option<&MonitorId>) -> bool {
+        WIDGET_MANAGER
+            .deployments
+            .get(&WidgetId::known_weg(), |deploy| {
+                deploy.pods.any(|(label, _)| {
+                    monitor_id.is_none() || label.monitor_id.as_ref() == monitor_id
+                })
+            })
+            .unwrap_or(false)
+    }
+
+    /// Returns the webview of the dock deployed on the monitor, if any.
+    pub fn webview_on_monitor(monitor_id: &MonitorId) -> Option<tauri::WebviewWindow> {
+        let raw = WIDGET_MANAGER
+            .deployments
+            .get(&WidgetId::known_weg(), |deploy| {
+                let mut raw = None;
+                deploy.pods.for_each(|(label, _)| {
+                    if label.monitor_id.as_ref() == Some(monitor_id) {
+                        raw = Some(label.raw.clone());
+                    }
+                });
+                raw
+            })
+            .flatten()?;
+        get_app_handle().get_webview_window(&raw)
+    }
+
+    fn apply_input_capture(monitor_id: &MonitorId, interactive: bool) -> Result<()> {
+        let webview = Self::webview_on_monitor(monitor_id)
+            .ok_or_else(|| format!("There is no dock on monitor {monitor_id}"))?;
+        webview.set_ignore_cursor_events(!interactive)?;
+        Ok(())
+    }
+
+    /// Makes the whole dock window capture (or let through) the mouse input, from here on
+    /// the backend owns the input state of the dock and hovering its interactive regions
+    /// makes it capture the input too.
+    pub fn set_interactive(monitor_id: &MonitorId, interactive: bool) -> Result<()> {
+        let effective = INPUT_CAPTURE.get_or_default(monitor_id.clone(), |capture| {
+            capture.requested = Some(interactive);
+            capture.effective()
+        });
+        match effective {
+            Some(effective) => Self::apply_input_capture(monitor_id, effective),
+            None => Ok(()),
+        }
+    }
+
+    /// Replaces the regions of the dock that are forced to be interactive while hovered,
+    /// an empty list removes them.
+    pub fn set_interactive_regions(monitor_id: &MonitorId, rects: Vec<Rect>) -> Result<()> {
+        let change = INPUT_CAPTURE.get_or_default(monitor_id.clone(), |capture| {
+            let before = capture.effective();
+            if rects.is_empty() {
+                capture.is_hovered = false;
+            }
+            capture.regions = rects;
+            let after = capture.effective();
+            after.filter(|_| before != after)
+        });
+        match change {
+            Some(interactive) => Self::apply_input_capture(monitor_id, interactive),
+            None => Ok(()),
+        }
+    }
+
+    /// Drops the input capture state of the docks that are no longer deployed.
+    pub fn forget_removed_instances() {
+        INPUT_CAPTURE.retain(|(monitor_id, _)| Self::has_instance(Some(monitor_id)));
+    }
+
+    /// Toggles the input of the docks when the cursor enters or leaves their interactive
+    /// regions, only for docks whose input state is owned by the backend.
+    pub fn on_cursor_moved(pos: &Point) {
+        if INPUT_CAPTURE.is_empty() {
+            return;
+        }
+
+        let mut changes = Vec::new();
+        INPUT_CAPTURE.for_each(|(monitor_id, capture)| {
+            let is_hovered = capture.regions.iter().any(|r| r.contains(pos));
+            if capture.is_hovered == is_hovered {
+                return;
+            }
+            let before = capture.effective();
+            capture.is_hovered = is_hovered;
+            let after = capture.effective();
+            if let Some(interactive) = after.filter(|_| before != after) {
+                changes.push((monitor_id.clone(), interactive));
+            }
+        });
+
+        for (monitor_id, interactive) in changes {
+            Self::apply_input_capture(&monitor_id, interactive).log_error();
+        }
+    }
+
+    /// Returns the rect of the native taskbar placed on the monitor, if any.
+    pub fn native_taskbar_rect(monitor_id: &MonitorId) -> Option<Rect> {
+        let mut rect = None;
+        let _ = WindowEnumerator::new().for_each(|window| {
+            if rect.is_none()
+                && TASKBAR_CLASS.contains(&window.class().as_str())
+                && window.monitor_id() == *monitor_id
+            {
+                rect = window.outer_rect().ok();
+            }
+        });
+        rect
+    }
 }
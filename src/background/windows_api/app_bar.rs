@@ -1,6 +1,7 @@
 use parking_lot::Mutex;
 use seelen_core::system_state::AppBarEdge;
-use std::sync::LazyLock;
+use serde::{Deserialize, Serialize};
+use std::{path::PathBuf, sync::LazyLock};
 use windows::Win32::{
     Foundation::{HWND, LPARAM, RECT},
     UI::Shell::{
@@ -9,10 +10,53 @@ use windows::Win32::{
     },
 };
 
-use crate::{error::Result, trace_lock};
+use crate::{
+    error::{Result, ResultLogExt},
+    trace_lock,
+    utils::{atomic_write_file, constants::SEELEN_COMMON},
+    windows_api::window::Window,
+};
 
 static REGISTERED_BARS: LazyLock<Mutex<Vec<isize>>> = LazyLock::new(|| Mutex::new(Vec::new()));
 
+/// App bar registered by a Seelen instance. These are persisted so the bars of an instance
+/// that didn't exit cleanly can be removed on the next start, otherwise the work area stays
+/// shrunk without a visible bar.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PersistedBar {
+    hwnd: isize,
+    pid: u32,
+    /// left, top, right, bottom
+    rect: [i32; 4],
+}
+
+fn persisted_bars_path() -> PathBuf {
+    SEELEN_COMMON.app_cache_dir().join("app_bars.json")
+}
+
+fn read_persisted_bars() -> Vec<PersistedBar> {
+    std::fs::read_to_string(persisted_bars_path())
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn write_persisted_bars(bars: &[PersistedBar]) -> Result<()> {
+    atomic_write_file(
+        &persisted_bars_path(),
+        serde_json::to_string(bars)?.as_bytes(),
+    )
+}
+
+/// the list is shared by all the running instances, so only the entry of `hwnd` is touched.
+/// Called only when a bar is added or removed, repositioning a registered bar doesn't persist.
+fn update_persisted_bar(hwnd: isize, bar: Option<PersistedBar>) {
+    let mut bars = read_persisted_bars();
+    bars.retain(|b| b.hwnd != hwnd);
+    bars.extend(bar);
+    write_persisted_bars(&bars).log_error();
+}
+
 /// https://learn.microsoft.com/en-us/windows/win32/shell/abm-setstate#parameters
 #[derive(Debug, Clone, Copy)]
 pub enum AppBarDataState {
@@ -71,6 +115,37 @@ impl AppBarData {
         self.0.rc = rect;
     }
 
+    /// Removes the bars registered by previous Seelen instances that are no longer running.
+    /// Bars still owned by a live instance (e.g. overlapping during a restart) are kept.
+    pub fn remove_orphaned_bars() {
+        let _guard = trace_lock!(REGISTERED_BARS);
+        let (alive, orphaned): (Vec<_>, Vec<_>) =
+            read_persisted_bars().into_iter().partition(|bar| {
+                let window = Window::from(bar.hwnd);
+                window.is_window() && window.process().id() == bar.pid
+            });
+
+        if orphaned.is_empty() {
+            return;
+        }
+
+        for bar in orphaned {
+            // the handle could have been reused by a window of another process
+            if Window::from(bar.hwnd).is_window() {
+                continue;
+            }
+            log::info!(
+                "Removing orphaned app bar {:x} of pid {} at {:?}",
+                bar.hwnd,
+                bar.pid,
+                bar.rect
+            );
+            let mut data = Self::from_handle(HWND(bar.hwnd as _)).0;
+            unsafe { SHAppBarMessage(ABM_REMOVE, &mut data) };
+        }
+        write_persisted_bars(&alive).log_error();
+    }
+
     pub fn register_as_new_bar(&mut self) -> Result<()> {
         let mut data = self.0;
         let addr = data.hWnd.0 as isize;
@@ -82,18 +157,35 @@ impl AppBarData {
                 return Err("Failed to register App Bar".into());
             }
             guard.push(addr);
+            let rc = data.rc;
+            update_persisted_bar(
+                addr,
+                Some(PersistedBar {
+                    hwnd: addr,
+                    pid: std::process::id(),
+                    rect: [rc.left, rc.top, rc.right, rc.bottom],
+                }),
+            );
         }
 
         unsafe { SHAppBarMessage(ABM_SETPOS, &mut data) };
         Ok(())
     }
 
+    pub fn is_registered(&self) -> bool {
+        let addr = self.0.hWnd.0 as isize;
+        trace_lock!(REGISTERED_BARS).contains(&addr)
+    }
+
     pub fn unregister_bar(&mut self) -> Result<()> {
         let mut data = self.0;
         let addr = data.hWnd.0 as isize;
         let mut guard = trace_lock!(REGISTERED_BARS);
         unsafe { SHAppBarMessage(ABM_REMOVE, &mut data) };
-        guard.retain(|x| *x != addr);
+        if guard.contains(&addr) {
+            guard.retain(|x| *x != addr);
+            update_persisted_bar(addr, None);
+        }
         Ok(())
     }
 }
@@ -47,7 +47,8 @@ use windows::{
             PHYSICAL_MONITOR,
         },
         Foundation::{
-            HANDLE, HMODULE, HWND, LPARAM, LUID, MAX_PATH, POINT, RECT, STATUS_SUCCESS, WPARAM,
+            ERROR_CANCELLED, FILETIME, HANDLE, HMODULE, HWND, LPARAM, LUID, MAX_PATH, POINT, RECT,
+            STATUS_SUCCESS, WPARAM,
         },
         Graphics::{
             Dwm::{
@@ -56,8 +57,9 @@ use windows::{
                 DWM_CLOAKED_INHERITED, DWM_CLOAKED_SHELL,
             },
             Gdi::{
-                EnumDisplayMonitors, GetMonitorInfoW, MonitorFromPoint, MonitorFromWindow,
-                HMONITOR, MONITORENUMPROC, MONITORINFOEXW, MONITOR_DEFAULTTOPRIMARY,
+                ClientToScreen, EnumDisplayMonitors, GetMonitorInfoW, MonitorFromPoint,
+                MonitorFromWindow, HMONITOR, MONITORENUMPROC, MONITORINFOEXW,
+                MONITOR_DEFAULTTOPRIMARY,
             },
         },
         Security::{
@@ -85,9 +87,9 @@ use windows::{
             Shutdown::{ExitWindowsEx, LockWorkStation, EXIT_WINDOWS_FLAGS, SHUTDOWN_REASON},
             SystemInformation::{GetComputerNameExW, COMPUTER_NAME_FORMAT},
             Threading::{
-                GetCurrentProcess, GetCurrentProcessId, GetCurrentThreadId, OpenProcess,
-                OpenProcessToken, QueryFullProcessImageNameW, PROCESS_ACCESS_RIGHTS,
-                PROCESS_NAME_WIN32, PROCESS_QUERY_LIMITED_INFORMATION,
+                GetCurrentProcess, GetCurrentProcessId, GetCurrentThreadId, GetProcessTimes,
+                OpenProcess, OpenProcessToken, QueryFullProcessImageNameW, PROCESS_ACCESS_RIGHTS,
+                PROCESS_BASIC_INFORMATION, PROCESS_NAME_WIN32, PROCESS_QUERY_LIMITED_INFORMATION,
             },
         },
         UI::{
@@ -97,18 +99,19 @@ use windows::{
                 PropertiesSystem::{IPropertyStore, SHGetPropertyStoreForWindow, GPS_DEFAULT},
                 SHCreateItemFromParsingName, SHGetKnownFolderItem, SHGetKnownFolderPath,
                 SHLoadIndirectString, ShellExecuteExW, ShellLink, VirtualDesktopManager,
-                KF_FLAG_DEFAULT, SHELLEXECUTEINFOW, SIGDN_NORMALDISPLAY,
+                KF_FLAG_DEFAULT, SHELLEXECUTEINFOW, SIGDN_NORMALDISPLAY, SLR_NOUPDATE, SLR_NO_UI,
             },
             WindowsAndMessaging::{
-                FindWindowExW, GetClassNameW, GetDesktopWindow, GetForegroundWindow, GetParent,
-                GetWindow, GetWindowLongW, GetWindowRect, GetWindowTextW, GetWindowThreadProcessId,
-                IsIconic, IsWindow, IsWindowVisible, IsZoomed, PostMessageW, SendMessageW,
-                SetForegroundWindow, SetWindowPos, ShowWindow, ShowWindowAsync,
-                SystemParametersInfoW, GWL_EXSTYLE, GWL_STYLE, GW_OWNER, SET_WINDOW_POS_FLAGS,
-                SHOW_WINDOW_CMD, SPIF_SENDCHANGE, SPIF_UPDATEINIFILE, SPI_GETDESKWALLPAPER,
-                SPI_SETDESKWALLPAPER, SWP_ASYNCWINDOWPOS, SWP_NOACTIVATE, SWP_NOMOVE, SWP_NOSIZE,
-                SWP_NOZORDER, SW_SHOWNORMAL, SYSTEM_PARAMETERS_INFO_UPDATE_FLAGS, WINDOW_EX_STYLE,
-                WINDOW_STYLE, WS_SIZEBOX, WS_THICKFRAME,
+                FindWindowExW, GetClassNameW, GetClientRect, GetDesktopWindow, GetForegroundWindow,
+                GetParent, GetWindow, GetWindowLongW, GetWindowRect, GetWindowTextW,
+                GetWindowThreadProcessId, IsIconic, IsWindow, IsWindowVisible, IsZoomed,
+                PostMessageW, SendMessageW, SetForegroundWindow, SetWindowPos, ShowWindow,
+                ShowWindowAsync, SystemParametersInfoW, GWL_EXSTYLE, GWL_STYLE, GW_OWNER,
+                SET_WINDOW_POS_FLAGS, SHOW_WINDOW_CMD, SPIF_SENDCHANGE, SPIF_UPDATEINIFILE,
+                SPI_GETDESKWALLPAPER, SPI_SETDESKWALLPAPER, SWP_ASYNCWINDOWPOS, SWP_NOACTIVATE,
+                SWP_NOMOVE, SWP_NOSIZE, SWP_NOZORDER, SW_SHOWNORMAL,
+                SYSTEM_PARAMETERS_INFO_UPDATE_FLAGS, WINDOW_EX_STYLE, WINDOW_STYLE, WS_SIZEBOX,
+                WS_THICKFRAME,
             },
         },
     },
@@ -144,6 +147,15 @@ macro_rules! hstring {
     };
 }
 
+/// Max time spent by the shell trying to find a moved shortcut target.
+const LNK_RESOLVE_TIMEOUT_MS: u32 = 1000;
+
+/// Silent resolution that does not modify the lnk file. On SLR_NO_UI the high word is the
+/// timeout in milliseconds.
+fn lnk_resolve_flags() -> u32 {
+    SLR_NO_UI.0 as u32 | SLR_NOUPDATE.0 as u32 | (LNK_RESOLVE_TIMEOUT_MS << 16)
+}
+
 pub struct WindowsApi {}
 impl WindowsApi {
     pub fn module_handle_w() -> Result<HMODULE> {
@@ -274,6 +286,23 @@ impl WindowsApi {
             && window_rect.bottom >= rc_monitor.bottom - TOLERANCE)
     }
 
+    /// Browsers keep their chrome window on HTML5 fullscreen (videos), so the styles check
+    /// is not reliable for them. Instead both the DWM frame and the client area should
+    /// cover the whole monitor.
+    pub fn is_client_area_fullscreen(hwnd: HWND) -> Result<bool> {
+        let rc_monitor = WindowsApi::monitor_rect(WindowsApi::monitor_from_window(hwnd))?;
+        let frame_rect = WindowsApi::get_inner_window_rect(hwnd)?;
+        let client_rect = WindowsApi::get_client_rect_on_screen(hwnd)?;
+
+        let covers_monitor = |rect: &RECT| {
+            rect.left <= rc_monitor.left
+                && rect.top <= rc_monitor.top
+                && rect.right >= rc_monitor.right
+                && rect.bottom >= rc_monitor.bottom
+        };
+        Ok(covers_monitor(&frame_rect) && covers_monitor(&client_rect))
+    }
+
     pub fn is_cloaked(hwnd: HWND) -> Result<bool> {
         let mut cloaked: u32 = 0;
         Self::dwm_get_window_attribute(hwnd, DWMWA_CLOAKED, &mut cloaked)?;
@@ -494,6 +523,76 @@ impl WindowsApi {
         Ok(path.to_os_string())
     }
 
+    /// Creation time of the process as FILETIME ticks. Together with the process id, it
+    /// identifies a process even after its id is reused.
+    pub fn get_process_creation_time(process_id: u32) -> Result<u64> {
+        let handle = Self::open_process(PROCESS_QUERY_LIMITED_INFORMATION, false, process_id)?;
+        let mut creation = FILETIME::default();
+        let mut exit = FILETIME::default();
+        let mut kernel = FILETIME::default();
+        let mut user = FILETIME::default();
+        unsafe { GetProcessTimes(*handle, &mut creation, &mut exit, &mut kernel, &mut user)? };
+        Ok(((creation.dwHighDateTime as u64) << 32) | creation.dwLowDateTime as u64)
+    }
+
+    /// Id of the process that created the given one, it could be already gone.
+    pub fn get_parent_process_id(process_id: u32) -> Result<u32> {
+        let handle = Self::open_process(PROCESS_QUERY_LIMITED_INFORMATION, false, process_id)?;
+        let mut info = PROCESS_BASIC_INFORMATION::default();
+        let status = unsafe {
+            NtQueryInformationProcess(
+                *handle,
+                ProcessBasicInformation,
+                &mut info as *mut _ as _,
+                std::mem::size_of::<PROCESS_BASIC_INFORMATION>() as _,
+                std::ptr::null_mut(),
+            )
+        };
+        if status != STATUS_SUCCESS {
+            return Err(format!(
+                "NtQueryInformationProcess failed with status: {:x}",
+                status.0
+            )
+            .into());
+        }
+        Ok(info.InheritedFromUniqueProcessId as u32)
+    }
+
+    /// Exe names of the parent processes chain of the given process, nearest first.
+    ///
+    /// The chain stops on the first parent that can't be inspected or that started after its
+    /// child, as the parent exited and its id was reused by an unrelated process.
+    pub fn get_process_ancestors(process_id: u32) -> Result<Vec<String>> {
+        let mut ancestors = Vec::new();
+        let mut child = process_id;
+        let mut child_created_at = Self::get_process_creation_time(child)?;
+
+        while ancestors.len() < 32 {
+            let Ok(parent) = Self::get_parent_process_id(child) else {
+                break;
+            };
+            if parent == 0 || parent == child {
+                break;
+            }
+            let Ok(parent_created_at) = Self::get_process_creation_time(parent) else {
+                break;
+            };
+            if parent_created_at > child_created_at {
+                break;
+            }
+            let Ok(path) = Self::exe_path_by_process(parent) else {
+                break;
+            };
+            let Some(exe) = Path::new(&path).file_name() else {
+                break;
+            };
+            ancestors.push(exe.to_string_lossy().to_string());
+            child = parent;
+            child_created_at = parent_created_at;
+        }
+        Ok(ancestors)
+    }
+
     pub fn get_class(hwnd: HWND) -> Result<String> {
         let mut text: [u16; 512] = [0; 512];
         let len = unsafe { GetClassNameW(hwnd, &mut text) };
@@ -582,6 +681,11 @@ impl WindowsApi {
             let persist_file: IPersistFile = shell_link.cast()?;
             unsafe { persist_file.Load(PCWSTR(lnk_wide.as_ptr()), STGM_READ)? };
 
+            // Try to fix moved targets (common on launchers stubs and game shortcuts)
+            if let Err(err) = unsafe { shell_link.Resolve(HWND::default(), lnk_resolve_flags()) } {
+                log::trace!("Could not resolve link {lnk_path:?}: {err}");
+            }
+
             let mut target_path = WindowsString::new_to_fill(1024);
             let mut idk = WIN32_FIND_DATAW::default();
             unsafe { shell_link.GetPath(target_path.as_mut_slice(), &mut idk, 0)? };
@@ -706,6 +810,21 @@ impl WindowsApi {
         Ok(rect)
     }
 
+    /// Get the client area rect in screen coordinates
+    pub fn get_client_rect_on_screen(hwnd: HWND) -> Result<RECT> {
+        let mut rect = RECT::default();
+        unsafe { GetClientRect(hwnd, &mut rect)? };
+
+        let mut origin = POINT::default();
+        unsafe { ClientToScreen(hwnd, &mut origin).ok()? };
+
+        rect.left += origin.x;
+        rect.top += origin.y;
+        rect.right += origin.x;
+        rect.bottom += origin.y;
+        Ok(rect)
+    }
+
     fn get_window_thickness(hwnd: HWND) -> u32 {
         let mut thickness = 0u32;
         let _ = Self::dwm_get_window_attribute(
@@ -1041,6 +1160,15 @@ impl WindowsApi {
     ) -> Result<()> {
         log::trace!("Running: {program:?} with args: {args:?} in working dir: {working_dir:?}");
 
+        // the runas verb is ignored for packaged apps, they would be opened as a normal user
+        if elevated {
+            if let Some(umid) = program.strip_prefix("shell:AppsFolder\\") {
+                if Self::is_uwp_package_id(umid) {
+                    return Err("Packaged (UWP) apps can't be run as administrator".into());
+                }
+            }
+        }
+
         let program = WindowsString::from_str(&program);
         let args = args.map(WindowsString::from);
         let working_dir = working_dir.map(WindowsString::from);
@@ -1066,7 +1194,27 @@ impl WindowsApi {
             ..Default::default()
         };
 
-        unsafe { ShellExecuteExW(&mut info)? };
+        if let Err(err) = unsafe { ShellExecuteExW(&mut info) } {
+            if elevated && err.code() == ERROR_CANCELLED.to_hresult() {
+                return Err("The administrator permission request was cancelled".into());
+            }
+            return Err(err.into());
+        }
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lnk_resolve_flags_are_silent_and_keep_the_timeout_on_the_high_word() {
+        let flags = lnk_resolve_flags();
+        assert_eq!(flags >> 16, LNK_RESOLVE_TIMEOUT_MS);
+        assert_ne!(flags & SLR_NO_UI.0 as u32, 0);
+        assert_ne!(flags & SLR_NOUPDATE.0 as u32, 0);
+        // the timeout must not overflow into the flags
+        assert_eq!(flags & 0xFFFF, SLR_NO_UI.0 as u32 | SLR_NOUPDATE.0 as u32);
+    }
+}
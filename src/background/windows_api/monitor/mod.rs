@@ -155,6 +155,22 @@ impl Monitor {
         })
     }
 
+    /// Win32 device name, e.g. `\\.\DISPLAY1`, not stable across display changes.
+    pub fn device_name(&self) -> Result<String> {
+        Ok(WindowsString::from_slice(&self.info()?.szDevice).to_string())
+    }
+
+    /// Rect of the monitor excluding the space reserved by app bars (taskbar, toolbar, etc).
+    pub fn work_area(&self) -> Result<Rect> {
+        let rect = WindowsApi::monitor_info(self.0)?.monitorInfo.rcWork;
+        Ok(Rect {
+            left: rect.left,
+            top: rect.top,
+            right: rect.right,
+            bottom: rect.bottom,
+        })
+    }
+
     pub fn scale_factor(&self) -> Result<f64> {
         let monitor_scale_factor = WindowsApi::get_monitor_scale_factor(self.0)?;
         let text_scale_factor = SystemSettings::instance().get_text_scale_factor()?;
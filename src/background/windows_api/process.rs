@@ -1,4 +1,4 @@
-use std::path::PathBuf;
+use std::{path::PathBuf, sync::LazyLock};
 
 use seelen_core::system_state::ProcessInformation;
 use windows::{
@@ -11,7 +11,7 @@ use windows::{
 };
 use windows_core::Owned;
 
-use crate::error::Result;
+use crate::{error::Result, utils::lock_free::SyncHashMap};
 
 use super::{string_utils::WindowsString, types::AppUserModelId, window::Window, WindowsApi};
 
@@ -29,6 +29,9 @@ pub enum ProcessInformationFlag {
     IsSubsystemProcess = 0x100,
 }
 
+/// Ancestors exe names keyed by process id and creation time, as process ids are reused.
+static ANCESTORS: LazyLock<SyncHashMap<(u32, u64), Vec<String>>> = LazyLock::new(SyncHashMap::new);
+
 pub struct Process(u32);
 
 impl Process {
@@ -91,22 +94,54 @@ impl Process {
             .to_string())
     }
 
+    /// Friendly name of the program from its version info (`FileDescription`), falling back
+    /// to the exe stem. Cached by path, as reading it from the shell is slow.
     pub fn program_display_name(&self) -> Result<String> {
+        static DISPLAY_NAMES: LazyLock<SyncHashMap<PathBuf, String>> =
+            LazyLock::new(SyncHashMap::new);
+
         let path = self.program_path()?;
-        match WindowsApi::get_executable_display_name(&path) {
-            Ok(name) => Ok(name.trim_end_matches(".exe").to_owned()),
-            Err(_) => Ok(path
+        if let Some(name) = DISPLAY_NAMES.get(&path, |name| name.clone()) {
+            return Ok(name);
+        }
+
+        let name = match WindowsApi::get_executable_display_name(&path) {
+            Ok(name) => name.trim_end_matches(".exe").to_owned(),
+            Err(_) => path
                 .file_stem()
                 .ok_or("there is no file stem")?
                 .to_string_lossy()
-                .to_string()),
+                .to_string(),
+        };
+        DISPLAY_NAMES.upsert(path, name.clone());
+        Ok(name)
+    }
+
+    /// Exe names of the parent processes chain, nearest first.
+    /// Cached by process, as the parent of a process never changes.
+    pub fn ancestors_exe_names(&self) -> Vec<String> {
+        let Ok(created_at) = WindowsApi::get_process_creation_time(self.0) else {
+            return Vec::new();
+        };
+        let key = (self.0, created_at);
+        if let Some(ancestors) = ANCESTORS.get(&key, |a| a.clone()) {
+            return ancestors;
         }
+        let ancestors = WindowsApi::get_process_ancestors(self.0).unwrap_or_default();
+        ANCESTORS.upsert(key, ancestors.clone());
+        ancestors
+    }
+
+    /// Drops the cached ancestors of the process, to be called once it is no longer tracked.
+    pub fn forget_ancestors(&self) {
+        ANCESTORS.retain(|((process_id, _), _)| *process_id != self.0);
     }
 
     pub fn to_serializable(&self) -> ProcessInformation {
         ProcessInformation {
             id: self.0,
             path: self.program_path().ok(),
+            ancestors: self.ancestors_exe_names(),
         }
     }
 
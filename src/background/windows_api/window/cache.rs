@@ -3,15 +3,37 @@
 //! are not standard windows events, so we handle cached windows data to check for these changes, and emit
 //! synthetic events for them.
 
-use seelen_core::system_state::{FocusedApp, Relaunch, RelaunchArguments, UserAppWindow};
+use seelen_core::{
+    state::AppExtraFlag,
+    system_state::{FocusedApp, Relaunch, RelaunchArguments, UserAppWindow},
+};
 
-use crate::{utils::get_parts_of_inline_command, windows_api::types::AppUserModelId};
+use crate::{
+    utils::{get_parts_of_inline_command, short_title},
+    windows_api::types::AppUserModelId,
+};
 
 use super::Window;
 
+static CONSOLE_CLASSES: [&str; 2] = ["ConsoleWindowClass", "CASCADIA_HOSTING_WINDOW_CLASS"];
+static CONSOLE_EXES: [&str; 6] = [
+    "cmd.exe",
+    "powershell.exe",
+    "pwsh.exe",
+    "conhost.exe",
+    "OpenConsole.exe",
+    "WindowsTerminal.exe",
+];
+
+static UWP_HOST_EXES: [&str; 1] = ["ApplicationFrameHost.exe"];
+
 impl Window {
     pub fn to_serializable(self: &Window) -> UserAppWindow {
-        let umid = self.app_user_model_id();
+        // UWP apps are hosted by ApplicationFrameHost.exe, the real app is the creator of the frame
+        let frame_creator = self.get_frame_creator().ok().flatten();
+        let umid = self
+            .app_user_model_id()
+            .or_else(|| frame_creator.and_then(|creator| creator.app_user_model_id()));
         let mut prevent_pinning = false;
 
         let relaunch = match umid {
@@ -36,20 +58,61 @@ impl Window {
             _ => None,
         };
 
+        let title = self.title();
+        let app_name = self.app_display_name().unwrap_or_default();
+
         UserAppWindow {
             hwnd: self.address(),
             monitor: self.monitor().stable_id().unwrap_or_default(),
-            title: self.title(),
-            app_name: self.app_display_name().unwrap_or_default(),
+            short_title: short_title(&title, &app_name),
+            title,
+            class: self.class(),
+            app_name,
             is_iconic: self.is_minimized(),
             is_zoomed: self.is_maximized(),
             is_fullscreen: self.is_fullscreen(),
+            is_topmost: self.is_topmost(),
             umid: umid.map(|umid| umid.to_string()),
-            process: self.process().to_serializable(),
+            process: frame_creator.unwrap_or(*self).process().to_serializable(),
             prevent_pinning,
             relaunch,
             rect: self.inner_rect().ok(),
             last_foreground_at: 0,
+            fallback_icon: self.missing_icon_key(),
+            requires_attention: false,
+            pinned_by_config: self.is_pinned_by_config(),
+            // windows are added to the active workspace, the virtual desktops keep it updated
+            on_current_desktop: true,
+        }
+    }
+
+    /// Returns the custom icon key to be shown instead of the generic missing icon
+    /// when the icon of this window can not be extracted.
+    fn missing_icon_key(&self) -> Option<String> {
+        let class = self.class();
+        let exe = self.process().program_exe_name().unwrap_or_default();
+        let is_exe = |list: &[&str]| list.iter().any(|e| e.eq_ignore_ascii_case(&exe));
+
+        if CONSOLE_CLASSES.contains(&class.as_str()) || is_exe(&CONSOLE_EXES) {
+            return Some("@seelen/weg::fallback-console".to_owned());
+        }
+
+        let is_appx = self.app_user_model_id().is_some_and(|umid| umid.is_appx());
+        if is_appx || is_exe(&UWP_HOST_EXES) {
+            return Some("@seelen/weg::fallback-uwp".to_owned());
+        }
+
+        None
+    }
+
+    fn is_pinned_by_config(&self) -> bool {
+        let to_validate = match self.get_frame_creator() {
+            Ok(Some(creator)) => creator,
+            _ => *self,
+        };
+        match to_validate.get_app_config() {
+            Ok(Some(config)) => config.options.contains(&AppExtraFlag::WegPinned),
+            _ => false,
         }
     }
 
@@ -70,6 +133,8 @@ impl Window {
             is_maximized: self.is_maximized(),
             is_fullscreened: self.is_fullscreen(),
             rect: self.inner_rect().ok(),
+            // resolved by the apps manager, it owns the interactable windows
+            app_window: None,
         }
     }
 }
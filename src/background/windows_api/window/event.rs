@@ -100,6 +100,8 @@ pub enum WinEvent {
     /// intended to reduce the amount of events processed by other listeners
     SynThrottledForegroundRectChange,
     SynDebouncedRectChange,
+    /// titles can change many times per second (ex: browsers or media players)
+    SynDebouncedNameChange,
 }
 
 impl From<u32> for WinEvent {
@@ -278,9 +280,19 @@ static LAZY_LOCATION_CHANGE_DEBOUNCE: LazyLock<PerWindowDebounce> = LazyLock::ne
     })
 });
 
+static LAZY_NAME_CHANGE_DEBOUNCE: LazyLock<PerWindowDebounce> = LazyLock::new(|| {
+    PerWindowDebounce::new(Duration::from_millis(150), |addr| {
+        let window = Window::from(addr);
+        HookManager::send((WinEvent::SynDebouncedNameChange, window));
+    })
+});
+
 impl WinEvent {
     pub fn debounce_as_needed(&self, origin: &Window) {
         match self {
+            Self::ObjectNameChange => {
+                LAZY_NAME_CHANGE_DEBOUNCE.call(origin.address());
+            }
             Self::ObjectLocationChange => {
                 if origin.is_focused() {
                     LAZY_LOCATION_CHANGE_EVENT.call(origin.address());
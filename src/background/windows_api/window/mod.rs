@@ -18,7 +18,10 @@ use windows::{
         Foundation::{HWND, RECT},
         UI::{
             Shell::FOLDERID_System,
-            WindowsAndMessaging::{SET_WINDOW_POS_FLAGS, SHOW_WINDOW_CMD, SW_RESTORE},
+            WindowsAndMessaging::{
+                SET_WINDOW_POS_FLAGS, SHOW_WINDOW_CMD, SWP_ASYNCWINDOWPOS, SW_MAXIMIZE, SW_RESTORE,
+                WS_EX_TOPMOST,
+            },
         },
     },
 };
@@ -102,6 +105,31 @@ impl Window {
         self.class() == "Chrome_WidgetWin_1"
     }
 
+    pub fn is_browser(&self) -> bool {
+        const BROWSERS: [&str; 7] = [
+            "chrome.exe",
+            "msedge.exe",
+            "firefox.exe",
+            "brave.exe",
+            "opera.exe",
+            "vivaldi.exe",
+            "zen.exe",
+        ];
+        self.process()
+            .program_exe_name()
+            .is_ok_and(|exe| BROWSERS.contains(&exe.to_lowercase().as_str()))
+    }
+
+    /// Focused browser playing a video on fullscreen, maximized windows are excluded
+    /// as their client area could cover the monitor when no taskbar reserves space.
+    fn is_browser_fullscreen(&self) -> bool {
+        self.is_focused()
+            && !self.is_maximized()
+            && !self.is_minimized()
+            && self.is_browser()
+            && WindowsApi::is_client_area_fullscreen(self.0).unwrap_or(false)
+    }
+
     /// Application user model id asigned to the window via property-store or inherited from the process
     ///
     /// https://learn.microsoft.com/en-us/windows/win32/properties/props-system-appusermodel-id
@@ -277,6 +305,11 @@ impl Window {
         WindowsApi::is_zoomed(self.0)
     }
 
+    /// "always on top" windows, set via `WS_EX_TOPMOST`
+    pub fn is_topmost(&self) -> bool {
+        WindowsApi::get_ex_styles(self.0).contains(WS_EX_TOPMOST)
+    }
+
     pub fn is_cloaked(&self) -> bool {
         WindowsApi::is_cloaked(self.0).unwrap_or(false)
     }
@@ -286,7 +319,7 @@ impl Window {
     }
 
     pub fn is_fullscreen(&self) -> bool {
-        WindowsApi::is_fullscreen(self.0).unwrap_or(false)
+        (WindowsApi::is_fullscreen(self.0).unwrap_or(false) || self.is_browser_fullscreen())
             && !self.is_desktop()
             && !self.process().is_seelen() // we ignore seelen widgets
     }
@@ -371,7 +404,6 @@ impl Window {
         }
     }
 
-    #[allow(dead_code)]
     pub fn set_position(&self, rect: &RECT, flags: SET_WINDOW_POS_FLAGS) -> Result<()> {
         if self.is_manageable_from_unelevated() {
             WindowsApi::set_position(self.hwnd(), None, rect, flags)
@@ -389,6 +421,53 @@ impl Window {
         }
     }
 
+    /// Moves the window to the same relative position on the work area of the target monitor,
+    /// scaling its size by the DPI difference between both monitors and keeping it maximized.
+    pub fn move_to_monitor(&self, target: &Monitor) -> Result<()> {
+        let source = self.monitor();
+        if source.handle() == target.handle() {
+            return Ok(());
+        }
+
+        // a minimized window is restored to its previous state, maximized included,
+        // so the maximized state can only be read after restoring it.
+        if self.is_minimized() {
+            self.show_window(SW_RESTORE)?;
+        }
+        let was_maximized = self.is_maximized();
+        if was_maximized {
+            // sync to read the restored rect below
+            self.show_window(SW_RESTORE)?;
+        }
+
+        let src_area = source.work_area()?;
+        let dst_area = target.work_area()?;
+        let scale = target.scale_factor()? / source.scale_factor()?;
+        let rect = self.outer_rect()?;
+
+        let width = (((rect.right - rect.left) as f64 * scale) as i32).min(dst_area.width());
+        let height = (((rect.bottom - rect.top) as f64 * scale) as i32).min(dst_area.height());
+        let left = (dst_area.left + ((rect.left - src_area.left) as f64 * scale) as i32)
+            .clamp(dst_area.left, dst_area.right - width);
+        let top = (dst_area.top + ((rect.top - src_area.top) as f64 * scale) as i32)
+            .clamp(dst_area.top, dst_area.bottom - height);
+
+        self.set_position(
+            &RECT {
+                left,
+                top,
+                right: left + width,
+                bottom: top + height,
+            },
+            SWP_ASYNCWINDOWPOS,
+        )?;
+
+        if was_maximized {
+            self.show_window_async(SW_MAXIMIZE)?;
+        }
+        Ok(())
+    }
+
     pub fn unminimize(&self) -> Result<()> {
         if self.is_minimized() {
             self.show_window(SW_RESTORE)?;
@@ -115,6 +115,8 @@ pub fn start_app_monitoring(app_just_launched: bool) {
         }
 
         log::error!("Seelen UI monitoring stopped after repeated failures.");
+        // exiting from here skips the shutdown tasks of main
+        crate::log_error!(crate::shutdown::restore_native_taskbar());
         exit(1);
     });
 }
@@ -81,7 +81,10 @@ async fn _process_action(command: SvcAction) -> Result<()> {
         SvcAction::StopShortcutRegistration => {
             crate::hotkeys::stop_shortcut_registration().await?;
         }
-        SvcAction::HideNativeTaskbar => crate::shutdown::hide_native_taskbar(),
+        SvcAction::HideNativeTaskbar {
+            keep_on_monitors,
+            refresh,
+        } => crate::shutdown::hide_native_taskbar(keep_on_monitors, refresh),
         SvcAction::RestoreNativeTaskbar => crate::shutdown::restore_native_taskbar()?,
     }
     Ok(())
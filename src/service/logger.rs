@@ -53,6 +53,14 @@ impl SluServiceLogger {
             }
 
             log::error!("A panic occurred:\n  Cause: {cause}\n  Location: {string_location}");
+
+            // a panic on the main thread will end the process, so the taskbar should not be left hidden
+            if std::thread::current().name() == Some("main") {
+                if let Err(err) = crate::shutdown::restore_native_taskbar() {
+                    log::error!("Failed to restore native taskbar: {err:?}");
+                }
+            }
+
             base_hook(info);
         }));
     }
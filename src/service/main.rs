@@ -77,6 +77,7 @@ pub fn exit(code: u32) {
 pub fn setup() -> Result<()> {
     WindowsApi::set_process_dpi_aware()?;
     WindowsApi::enable_privilege(SE_TCB_NAME)?;
+    log_error!(shutdown::restore_native_taskbar_if_left_hidden());
     ServiceIpc::start(crate::cli::processing::process_action)?;
 
     let app_just_launched = if !AppIpc::can_stablish_connection() {
@@ -1,8 +1,19 @@
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::{
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        LazyLock,
+    },
+};
+
+use parking_lot::Mutex;
 
 use windows::Win32::{
     Foundation::HWND,
-    UI::WindowsAndMessaging::{SW_HIDE, SW_SHOWNORMAL},
+    UI::{
+        Shell::FOLDERID_LocalAppData,
+        WindowsAndMessaging::{SW_HIDE, SW_SHOWNORMAL},
+    },
 };
 
 use crate::{
@@ -18,7 +29,36 @@ use crate::{
 /// when it was hidden by us (avoids unnecessary restores on settings changes/shutdown).
 static NATIVE_TASKBAR_HIDDEN: AtomicBool = AtomicBool::new(false);
 
+/// Taskbars that the user wants to keep visible, these are never touched.
+static KEPT_TASKBARS: LazyLock<Mutex<Vec<isize>>> = LazyLock::new(|| Mutex::new(Vec::new()));
+
+/// The hidden state is also persisted to disk, so a hard crash of the service
+/// (where no cleanup code runs) can be recovered on the next launch.
+fn hidden_marker_path() -> Result<PathBuf> {
+    Ok(WindowsApi::known_folder(FOLDERID_LocalAppData)?
+        .join("com.seelen.seelen-ui")
+        .join("native_taskbar_hidden"))
+}
+
+/// Cached to avoid a full windows enumeration on each hide/restore request.
+static TASKBAR_HANDLES: LazyLock<Mutex<Vec<isize>>> = LazyLock::new(|| Mutex::new(Vec::new()));
+
+/// Returns the cached taskbar handles, re-enumerating them if any of them is no longer valid.
 pub fn get_taskbars_handles() -> Result<Vec<HWND>> {
+    let cached: Vec<HWND> = TASKBAR_HANDLES
+        .lock()
+        .iter()
+        .map(|addr| HWND(*addr as _))
+        .collect();
+    if !cached.is_empty() && cached.iter().all(|hwnd| WindowsApi::is_window(*hwnd)) {
+        return Ok(cached);
+    }
+    refresh_taskbar_handles()
+}
+
+/// Forces the re-enumeration of the native taskbars, needed when new taskbars are created.
+pub fn refresh_taskbar_handles() -> Result<Vec<HWND>> {
+    log::trace!("Enumerating native taskbars");
     let mut founds = Vec::new();
     WindowEnumerator::new().for_each(|hwnd| {
         let class = WindowsApi::get_class(hwnd);
@@ -28,13 +68,55 @@ pub fn get_taskbars_handles() -> Result<Vec<HWND>> {
             founds.push(hwnd);
         }
     })?;
+    *TASKBAR_HANDLES.lock() = founds.iter().map(|hwnd| hwnd.0 as isize).collect();
     Ok(founds)
 }
 
-pub fn hide_native_taskbar() {
-    NATIVE_TASKBAR_HIDDEN.store(true, Ordering::Release);
-    std::thread::spawn(|| match get_taskbars_handles() {
-        Ok(handles) => {
+fn show_taskbar(hwnd: HWND) -> Result<()> {
+    AppBarData::from_handle(hwnd).set_state(AppBarDataState::AlwaysOnTop);
+    WindowsApi::show_window_async(hwnd.0 as isize, SW_SHOWNORMAL.0)
+}
+
+pub fn hide_native_taskbar(keep_on_monitors: Vec<isize>, refresh: bool) {
+    // the marker only needs to be written when the taskbar goes from shown to hidden
+    if !NATIVE_TASKBAR_HIDDEN.swap(true, Ordering::AcqRel) {
+        if let Err(err) = hidden_marker_path().and_then(|path| Ok(std::fs::write(path, [])?)) {
+            log::warn!("Failed to persist native taskbar hidden state: {err:?}");
+        }
+    }
+    std::thread::spawn(move || {
+        let handles = if refresh {
+            refresh_taskbar_handles()
+        } else {
+            get_taskbars_handles()
+        };
+        let handles = handles.map(|handles| {
+            let kept: Vec<isize> = handles
+                .iter()
+                .filter(|hwnd| keep_on_monitors.contains(&WindowsApi::monitor_from_window(**hwnd)))
+                .map(|hwnd| hwnd.0 as isize)
+                .collect();
+            *KEPT_TASKBARS.lock() = kept.clone();
+            (handles, kept)
+        });
+        hide_taskbars(handles);
+    });
+}
+
+fn hide_taskbars(handles: Result<(Vec<HWND>, Vec<isize>)>) {
+    match handles {
+        Ok((handles, keep)) => {
+            let (kept, handles): (Vec<HWND>, Vec<HWND>) = handles
+                .into_iter()
+                .partition(|h| keep.contains(&(h.0 as isize)));
+
+            // bars hidden before being added to the kept list
+            for hwnd in kept {
+                if !WindowsApi::is_window_visible(hwnd) {
+                    let _ = show_taskbar(hwnd);
+                }
+            }
+
             let mut attempts = 0;
             while attempts < 10 && NATIVE_TASKBAR_HIDDEN.load(Ordering::Acquire) {
                 for hwnd in &handles {
@@ -46,7 +128,7 @@ pub fn hide_native_taskbar() {
             }
         }
         Err(err) => log::error!("Failed to get taskbars handles: {err:?}"),
-    });
+    }
 }
 
 pub fn restore_native_taskbar() -> Result<()> {
@@ -57,9 +139,26 @@ pub fn restore_native_taskbar() -> Result<()> {
         return Ok(());
     }
 
+    if let Ok(path) = hidden_marker_path() {
+        let _ = std::fs::remove_file(path);
+    }
+
+    let kept = std::mem::take(&mut *KEPT_TASKBARS.lock());
     for hwnd in get_taskbars_handles()? {
-        AppBarData::from_handle(hwnd).set_state(AppBarDataState::AlwaysOnTop);
-        WindowsApi::show_window_async(hwnd.0 as isize, SW_SHOWNORMAL.0)?;
+        if kept.contains(&(hwnd.0 as isize)) {
+            continue;
+        }
+        show_taskbar(hwnd)?;
+    }
+    Ok(())
+}
+
+/// Restores the native taskbar if a previous session hid it and was not able to restore it.
+pub fn restore_native_taskbar_if_left_hidden() -> Result<()> {
+    if hidden_marker_path()?.exists() {
+        log::info!("Native taskbar was left hidden by a previous session, restoring it");
+        NATIVE_TASKBAR_HIDDEN.store(true, Ordering::Release);
+        restore_native_taskbar()?;
     }
     Ok(())
 }
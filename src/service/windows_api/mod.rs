@@ -6,6 +6,7 @@ use std::{ffi::OsString, os::windows::ffi::OsStringExt, path::PathBuf};
 
 use windows::Win32::{
     Foundation::{HANDLE, HWND, LUID},
+    Graphics::Gdi::{MonitorFromWindow, MONITOR_DEFAULTTONEAREST},
     Security::{
         AdjustTokenPrivileges, LookupPrivilegeValueW, SE_PRIVILEGE_ENABLED,
         TOKEN_ADJUST_PRIVILEGES, TOKEN_PRIVILEGES, TOKEN_QUERY,
@@ -20,9 +21,9 @@ use windows::Win32::{
         Input::KeyboardAndMouse::{keybd_event, KEYEVENTF_EXTENDEDKEY, KEYEVENTF_KEYUP, VK_MENU},
         Shell::{SHGetKnownFolderPath, KF_FLAG_DEFAULT},
         WindowsAndMessaging::{
-            FindWindowW, GetClassNameW, GetForegroundWindow, GetWindowTextW, SetForegroundWindow,
-            SetWindowPos, ShowWindow, ShowWindowAsync, SET_WINDOW_POS_FLAGS, SHOW_WINDOW_CMD,
-            SWP_NOACTIVATE, SWP_NOZORDER,
+            FindWindowW, GetClassNameW, GetForegroundWindow, GetWindowTextW, IsWindow,
+            IsWindowVisible, SetForegroundWindow, SetWindowPos, ShowWindow, ShowWindowAsync,
+            SET_WINDOW_POS_FLAGS, SHOW_WINDOW_CMD, SWP_NOACTIVATE, SWP_NOZORDER,
         },
     },
 };
@@ -190,6 +191,19 @@ impl WindowsApi {
         String::from_utf16_lossy(&text[..length])
     }
 
+    pub fn is_window(hwnd: HWND) -> bool {
+        unsafe { IsWindow(Some(hwnd)) }.as_bool()
+    }
+
+    pub fn is_window_visible(hwnd: HWND) -> bool {
+        unsafe { IsWindowVisible(hwnd) }.as_bool()
+    }
+
+    /// Returns the address of the monitor (HMONITOR) where the window is placed.
+    pub fn monitor_from_window(hwnd: HWND) -> isize {
+        unsafe { MonitorFromWindow(hwnd, MONITOR_DEFAULTTONEAREST) }.0 as isize
+    }
+
     pub fn get_title(hwnd: HWND) -> String {
         let mut text: [u16; 512] = [0; 512];
         let len = unsafe { GetWindowTextW(hwnd, &mut text) };
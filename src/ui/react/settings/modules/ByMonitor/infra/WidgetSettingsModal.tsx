@@ -6,6 +6,7 @@ import { Button, Modal } from "antd";
 import { type ReactNode, useState } from "react";
 
 import { WidgetConfiguration } from "../../resources/Widget/View.tsx";
+import { SeelenWegMonitorSettings } from "../../resources/Widget/seelenweg/infra.tsx";
 
 interface Props {
   widgetId: WidgetId;
@@ -25,7 +26,9 @@ export function WidgetSettingsModal({ widgetId, monitorId, title }: Props) {
         footer={null}
         centered
       >
-        <WidgetConfiguration widgetId={widgetId} monitorId={monitorId} />
+        <WidgetConfiguration widgetId={widgetId} monitorId={monitorId}>
+          {widgetId === "@seelen/weg" && <SeelenWegMonitorSettings monitorId={monitorId} />}
+        </WidgetConfiguration>
       </Modal>
       <Button type="default" onClick={() => setOpen(true)}>
         <Icon iconName="RiSettings4Fill" />
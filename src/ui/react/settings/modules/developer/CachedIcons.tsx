@@ -17,6 +17,7 @@ function resolveAsSrc(parent: string, icon: Icon): Icon {
     dark: icon.dark ? convertFileSrc(`${parent}\\${icon.dark}`) : null,
     mask: icon.mask ? convertFileSrc(`${parent}\\${icon.mask}`) : null,
     isAproximatelySquare: icon.isAproximatelySquare,
+    dominantColor: icon.dominantColor,
   };
 }
 
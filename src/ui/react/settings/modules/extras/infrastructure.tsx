@@ -139,7 +139,7 @@ export function Information() {
           <Button
             type="dashed"
             danger
-            onClick={() => invoke(SeelenCommand.StateDeleteCachedIcons)}
+            onClick={() => invoke(SeelenCommand.StateRefreshAllIcons)}
             style={{ width: "50px" }}
           >
             <Icon iconName="IoReload" size={12} />
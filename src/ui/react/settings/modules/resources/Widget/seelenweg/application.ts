@@ -1,5 +1,6 @@
 import { settings } from "../../../../state/mod";
-import type { SeelenWegSettings } from "@seelen-ui/lib/types";
+import type { SeelenWegSettings, WidgetId } from "@seelen-ui/lib/types";
+import { getMonitorWidgetConfig, patchWidgetMonitorConfig } from "../application.ts";
 
 /**
  * Patches the SeelenWeg configuration with partial updates.
@@ -28,3 +29,18 @@ export function patchWegConfig(patch: Partial<SeelenWegSettings>) {
 export function getWegConfig(): SeelenWegSettings {
   return settings.value.byWidget["@seelen/weg"];
 }
+
+/**
+ * Gets the SeelenWeg configuration for a monitor, the monitor patch overrides the global values.
+ */
+export function getWegMonitorConfig(monitorId: string): SeelenWegSettings {
+  const patch = getMonitorWidgetConfig(monitorId, "@seelen/weg" as WidgetId);
+  return { ...getWegConfig(), ...(patch || {}) } as SeelenWegSettings;
+}
+
+/**
+ * Patches the SeelenWeg configuration only for the given monitor.
+ */
+export function patchWegMonitorConfig(monitorId: string, patch: Partial<SeelenWegSettings>) {
+  patchWidgetMonitorConfig(monitorId, "@seelen/weg" as WidgetId, patch);
+}
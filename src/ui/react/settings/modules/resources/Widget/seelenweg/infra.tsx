@@ -1,11 +1,18 @@
-import { HideMode, SeelenWegMode, SeelenWegSide, WegMiddleClickAction } from "@seelen-ui/lib/types";
+import {
+  HideMode,
+  SeelenWegMode,
+  SeelenWegSide,
+  WegAlignment,
+  WegMiddleClickAction,
+  WegOverlapTrigger,
+} from "@seelen-ui/lib/types";
 import { Icon } from "libs/ui/react/components/Icon/index.tsx";
 import { $is_touch_primary } from "libs/ui/react/utils/signals";
 import { Button, InputNumber, Select, Switch, Tooltip } from "antd";
 import { useTranslation } from "react-i18next";
 
 import { OptionsFromEnum } from "../../../shared/utils/app.ts";
-import { getWegConfig, patchWegConfig } from "./application.ts";
+import { getWegConfig, getWegMonitorConfig, patchWegConfig, patchWegMonitorConfig } from "./application.ts";
 import { getDevTools } from "../../../developer/application.ts";
 
 import { SettingsGroup, SettingsOption, SettingsSubGroup } from "../../../../components/SettingsBox/index.tsx";
@@ -31,6 +38,16 @@ export const SeelenWegSettings = () => {
               onChange={(value) => patchWegConfig({ mode: value })}
             />
           </SettingsOption>
+          <SettingsOption>
+            <div>{t("weg.alignment.label")}</div>
+            <Select
+              style={{ width: "120px" }}
+              value={settings.alignment}
+              options={OptionsFromEnum(t, WegAlignment, "weg.alignment")}
+              onChange={(value) => patchWegConfig({ alignment: value })}
+              disabled={settings.mode === SeelenWegMode.FullWidth}
+            />
+          </SettingsOption>
           <SettingsOption>
             <div>{t("weg.dock_side")}</div>
             <Compact>
@@ -63,6 +80,37 @@ export const SeelenWegSettings = () => {
               max={40}
             />
           </SettingsOption>
+          <SettingsOption>
+            <div>{t("weg.thickness")}</div>
+            <InputNumber
+              value={settings.thickness}
+              onChange={(value) => patchWegConfig({ thickness: value || 0 })}
+              min={0}
+              max={300}
+            />
+          </SettingsOption>
+          <SettingsOption>
+            <div>{t("weg.always_on_top")}</div>
+            <Switch
+              checked={settings.alwaysOnTop}
+              onChange={(value) => patchWegConfig({ alwaysOnTop: value })}
+            />
+          </SettingsOption>
+          <SettingsOption>
+            <div>{t("weg.reserve_space")}</div>
+            <Switch
+              checked={settings.reserveSpace}
+              disabled={settings.coexistWithTaskbar}
+              onChange={(value) => patchWegConfig({ reserveSpace: value })}
+            />
+          </SettingsOption>
+          <SettingsOption>
+            <div>{t("weg.coexist_with_taskbar")}</div>
+            <Switch
+              checked={settings.coexistWithTaskbar}
+              onChange={(value) => patchWegConfig({ coexistWithTaskbar: value })}
+            />
+          </SettingsOption>
         </SettingsSubGroup>
       </SettingsGroup>
 
@@ -84,6 +132,37 @@ export const SeelenWegSettings = () => {
             </SettingsOption>
           }
         >
+          <SettingsOption>
+            <span>{t("weg.overlap_trigger.label")}</span>
+            <Select
+              style={{ width: "120px" }}
+              value={settings.overlapTrigger}
+              options={OptionsFromEnum(t, WegOverlapTrigger, "weg.overlap_trigger")}
+              onChange={(value) => patchWegConfig({ overlapTrigger: value })}
+              disabled={settings.hideMode !== HideMode.OnOverlap || isTouchPrimary}
+            />
+          </SettingsOption>
+          <SettingsOption>
+            <span>{t("weg.overlap_blacklist_exe")}</span>
+            <Select
+              mode="tags"
+              style={{ width: "200px" }}
+              value={settings.overlapBlacklistExe}
+              placeholder="app.exe"
+              onChange={(value) => patchWegConfig({ overlapBlacklistExe: value })}
+              disabled={settings.hideMode !== HideMode.OnOverlap || isTouchPrimary}
+            />
+          </SettingsOption>
+          <SettingsOption>
+            <span>{t("weg.overlap_blacklist_title")}</span>
+            <Select
+              mode="tags"
+              style={{ width: "200px" }}
+              value={settings.overlapBlacklistTitle}
+              onChange={(value) => patchWegConfig({ overlapBlacklistTitle: value })}
+              disabled={settings.hideMode !== HideMode.OnOverlap || isTouchPrimary}
+            />
+          </SettingsOption>
           <SettingsOption>
             <span>{t("weg.delay_to_show")} (ms)</span>
             <InputNumber
@@ -104,6 +183,26 @@ export const SeelenWegSettings = () => {
               onChange={(value) => patchWegConfig({ delayToHide: value || 0 })}
             />
           </SettingsOption>
+          <SettingsOption>
+            <span>{t("weg.reveal_zone_size")} (px)</span>
+            <InputNumber
+              value={settings.revealZoneSize}
+              min={1}
+              max={100}
+              disabled={settings.hideMode === HideMode.Never || isTouchPrimary}
+              onChange={(value) => patchWegConfig({ revealZoneSize: value || 1 })}
+            />
+          </SettingsOption>
+          <SettingsOption>
+            <span>{t("weg.animation_duration")} (ms)</span>
+            <InputNumber
+              value={settings.animationDuration}
+              min={0}
+              max={2000}
+              disabled={settings.hideMode === HideMode.Never || isTouchPrimary}
+              onChange={(value) => patchWegConfig({ animationDuration: value || 0 })}
+            />
+          </SettingsOption>
         </SettingsSubGroup>
       </SettingsGroup>
 
@@ -142,6 +241,90 @@ export const SeelenWegSettings = () => {
               onChange={(value) => patchWegConfig({ pinnedItemsVisibility: value })}
             />
           </SettingsOption>
+          <SettingsOption>
+            <div>{t("weg.items.filter.label")}</div>
+            <Select
+              style={{ width: "120px" }}
+              value={settings.itemsFilter}
+              options={[
+                {
+                  value: "PinnedAndRunning",
+                  label: t("weg.items.filter.pinned_and_running"),
+                },
+                {
+                  value: "RunningOnly",
+                  label: t("weg.items.filter.running_only"),
+                },
+                {
+                  value: "PinnedOnly",
+                  label: t("weg.items.filter.pinned_only"),
+                },
+              ]}
+              onChange={(value) => patchWegConfig({ itemsFilter: value })}
+            />
+          </SettingsOption>
+          <SettingsOption>
+            <div>{t("weg.items.order.label")}</div>
+            <Select
+              style={{ width: "120px" }}
+              value={settings.dockOrder}
+              options={[
+                {
+                  value: "Manual",
+                  label: t("weg.items.order.manual"),
+                },
+                {
+                  value: "MostRecentlyUsed",
+                  label: t("weg.items.order.most_recently_used"),
+                },
+              ]}
+              onChange={(value) => patchWegConfig({ dockOrder: value })}
+            />
+          </SettingsOption>
+          <SettingsOption>
+            <div>{t("weg.items.class_blacklist")}</div>
+            <Select
+              mode="tags"
+              style={{ width: "200px" }}
+              value={settings.classBlacklist}
+              placeholder="SplashWindow*"
+              onChange={(value) => patchWegConfig({ classBlacklist: value })}
+            />
+          </SettingsOption>
+          <SettingsOption>
+            <div>{t("weg.items.title_blacklist")}</div>
+            <Select
+              mode="tags"
+              style={{ width: "200px" }}
+              value={settings.titleBlacklist}
+              placeholder="Loading*"
+              onChange={(value) => patchWegConfig({ titleBlacklist: value })}
+            />
+          </SettingsOption>
+          <SettingsOption>
+            <div>{t("weg.items.ignore_process_tree")}</div>
+            <Select
+              mode="tags"
+              style={{ width: "200px" }}
+              value={settings.ignoreProcessTree}
+              placeholder="obs64.exe"
+              onChange={(value) => patchWegConfig({ ignoreProcessTree: value })}
+            />
+          </SettingsOption>
+          <SettingsOption>
+            <div>{t("weg.items.only_current_desktop")}</div>
+            <Switch
+              checked={settings.onlyCurrentDesktop}
+              onChange={(value) => patchWegConfig({ onlyCurrentDesktop: value })}
+            />
+          </SettingsOption>
+          <SettingsOption>
+            <div>{t("weg.items.batched_updates")}</div>
+            <Switch
+              checked={settings.batchedUpdates}
+              onChange={(value) => patchWegConfig({ batchedUpdates: value })}
+            />
+          </SettingsOption>
         </SettingsSubGroup>
       </SettingsGroup>
 
@@ -165,6 +348,16 @@ export const SeelenWegSettings = () => {
               max={40}
             />
           </SettingsOption>
+          <SettingsOption>
+            <div>{t("weg.items.max_items_per_line")}</div>
+            <InputNumber
+              value={settings.maxItemsPerLine}
+              onChange={(value) => patchWegConfig({ maxItemsPerLine: value || 0 })}
+              min={0}
+              max={100}
+              disabled={settings.mode === SeelenWegMode.FullWidth}
+            />
+          </SettingsOption>
           <SettingsOption>
             <div>{t("weg.items.show_window_title")}</div>
             <Switch
@@ -226,3 +419,53 @@ export const SeelenWegSettings = () => {
     </>
   );
 };
+
+/**
+ * Settings that can be overridden per monitor, the rest are inherited from the global config.
+ */
+export const SeelenWegMonitorSettings = ({ monitorId }: { monitorId: string }) => {
+  const settings = getWegMonitorConfig(monitorId);
+  const keptOn = getWegConfig().keepNativeTaskbarOn;
+  const isTouchPrimary = $is_touch_primary.value;
+
+  const { t } = useTranslation();
+
+  return (
+    <SettingsGroup>
+      <SettingsOption>
+        <div>{t("weg.dock_side")}</div>
+        <Compact>
+          {Object.values(SeelenWegSide).map((side) => (
+            <Button
+              key={side}
+              type={side === settings.position ? "primary" : "default"}
+              onClick={() => patchWegMonitorConfig(monitorId, { position: side })}
+            >
+              <Icon iconName={`CgToolbar${side}`} size={18} />
+            </Button>
+          ))}
+        </Compact>
+      </SettingsOption>
+      <SettingsOption>
+        <div>{t("weg.auto_hide")}</div>
+        <Select
+          style={{ width: "120px" }}
+          value={settings.hideMode}
+          options={OptionsFromEnum(t, HideMode, "weg.hide_mode")}
+          onChange={(value) => patchWegMonitorConfig(monitorId, { hideMode: value })}
+          disabled={isTouchPrimary}
+        />
+      </SettingsOption>
+      <SettingsOption>
+        <div>{t("weg.keep_native_taskbar")}</div>
+        <Switch
+          checked={keptOn.includes(monitorId)}
+          onChange={(value) => {
+            const others = keptOn.filter((id) => id !== monitorId);
+            patchWegConfig({ keepNativeTaskbarOn: value ? [...others, monitorId] : others });
+          }}
+        />
+      </SettingsOption>
+    </SettingsGroup>
+  );
+};
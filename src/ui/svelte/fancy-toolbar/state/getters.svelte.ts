@@ -1,6 +1,6 @@
 import { invoke, PluginList, SeelenCommand, SeelenEvent, Settings, subscribe, Widget } from "@seelen-ui/lib";
 import type { UserAppWindowColors } from "@seelen-ui/lib/types";
-import { lazyRune } from "libs/ui/svelte/utils";
+import { lazyRune, userAppWindowsRune } from "libs/ui/svelte/utils";
 
 export const currentMonitorId = Widget.getCurrent().decoded.monitorId!;
 
@@ -24,8 +24,7 @@ subscribe(SeelenEvent.GlobalMouseMove, mousePos.setByPayload);
 export const settings = lazyRune(() => Settings.getAsync());
 Settings.onChange((s) => (settings.value = s));
 
-export const interactables = lazyRune(() => invoke(SeelenCommand.GetUserAppWindows));
-subscribe(SeelenEvent.UserAppWindowsChanged, interactables.setByPayload);
+export const interactables = userAppWindowsRune();
 
 export const windowsColors = lazyRune<Record<number, UserAppWindowColors>>(
   () => invoke(SeelenCommand.GetUserAppWindowsColors),
@@ -54,6 +54,20 @@ $effect.root(() => {
           !isMouseOverEdge;
         flush = isTouchPrimary.value;
         break;
+      case HideMode.OnFullscreen:
+        hidden = !isTouchPrimary.value &&
+          windowsState.isFullscreenFocused &&
+          !isThisWebviewFocused.value &&
+          !isMouseOverEdge;
+        flush = isTouchPrimary.value;
+        break;
+      case HideMode.WhenMonitorUnfocused:
+        hidden = !isTouchPrimary.value &&
+          !windowsState.isMonitorFocused &&
+          !isThisWebviewFocused.value &&
+          !isMouseOverEdge;
+        flush = isTouchPrimary.value;
+        break;
     }
 
     if (_isDraggingItem) {
@@ -87,7 +87,8 @@ async function updateWidgetPosition() {
     return;
   }
 
-  if (hideMode === HideMode.Never || isTouch) {
+  // fullscreen windows are placed over the app bar so the work area can be reserved
+  if (hideMode === HideMode.Never || hideMode === HideMode.OnFullscreen || isTouch) {
     await invoke(SeelenCommand.RegisterAppBar, {
       rect,
       edge: position as unknown as AppBarEdge,
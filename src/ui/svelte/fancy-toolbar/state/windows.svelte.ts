@@ -49,6 +49,13 @@ const _currentMonitorMaximizedColors = $derived.by((): UserAppWindowColors | nul
   return windowsColors.value[maximized.hwnd] ?? null;
 });
 
+// maximized windows are not considered fullscreen as they keep their borders (WS_THICKFRAME)
+const _isFullscreenFocused = $derived(
+  !!focused.value?.isFullscreened && focused.value.monitor === widget.decoded.monitorId,
+);
+
+const _isMonitorFocused = $derived(focused.value?.monitor === widget.decoded.monitorId);
+
 class WindowsState {
   get topInteractableWindow() {
     return _topInteractableWindow;
@@ -61,6 +68,14 @@ class WindowsState {
   get isTbOverlapped() {
     return _isTbOverlapped;
   }
+
+  get isFullscreenFocused() {
+    return _isFullscreenFocused;
+  }
+
+  get isMonitorFocused() {
+    return _isMonitorFocused;
+  }
 }
 
 export const windowsState = new WindowsState();
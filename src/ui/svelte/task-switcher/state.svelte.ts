@@ -1,5 +1,6 @@
 import { invoke, SeelenCommand, SeelenEvent, subscribe, Widget } from "@seelen-ui/lib";
 import { lazyRune } from "libs/ui/svelte/utils/LazyRune.svelte.ts";
+import { userAppWindowsRune } from "libs/ui/svelte/utils/userAppWindows.svelte.ts";
 
 const widget = Widget.getCurrent();
 
@@ -8,14 +9,15 @@ const widget = Widget.getCurrent();
 let showing = $state(false);
 let autoConfirm = $state(false);
 
-let windows = lazyRune(async () =>
-  (await invoke(SeelenCommand.GetUserAppWindows)).toSorted(
-    (a, b) => b.lastForegroundAt - a.lastForegroundAt,
-  )
+let interactables = userAppWindowsRune();
+const windows = {
+  get value() {
+    return _sortedWindows;
+  },
+};
+const _sortedWindows = $derived(
+  interactables.value.toSorted((a, b) => b.lastForegroundAt - a.lastForegroundAt),
 );
-subscribe(SeelenEvent.UserAppWindowsChanged, ({ payload }) => {
-  windows.value = payload.toSorted((a, b) => b.lastForegroundAt - a.lastForegroundAt);
-});
 
 let previews = lazyRune(() => invoke(SeelenCommand.GetUserAppWindowsPreviews));
 subscribe(SeelenEvent.UserAppWindowsPreviewsChanged, previews.setByPayload);
@@ -28,7 +30,7 @@ subscribe(SeelenEvent.GlobalFocusChanged, (e) => {
 let monitors = lazyRune(() => invoke(SeelenCommand.SystemGetMonitors));
 subscribe(SeelenEvent.SystemMonitorsChanged, monitors.setByPayload);
 
-await Promise.all([windows.init(), previews.init(), focusedWinId.init(), monitors.init()]);
+await Promise.all([interactables.init(), previews.init(), focusedWinId.init(), monitors.init()]);
 
 let selectedWindow = $state<number | null>(focusedWinId.value ?? null);
 
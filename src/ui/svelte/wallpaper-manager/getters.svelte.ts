@@ -1,4 +1,4 @@
-import { lazyRune } from "libs/ui/svelte/utils";
+import { lazyRune, userAppWindowsRune } from "libs/ui/svelte/utils";
 import { invoke, SeelenCommand, SeelenEvent, subscribe } from "@seelen-ui/lib";
 
 export const settings = lazyRune(() => invoke(SeelenCommand.StateGetSettings, { path: null }));
@@ -7,8 +7,7 @@ subscribe(SeelenEvent.StateSettingsChanged, settings.setByPayload);
 export const focused = lazyRune(() => invoke(SeelenCommand.GetFocusedApp));
 subscribe(SeelenEvent.GlobalFocusChanged, focused.setByPayload);
 
-export const interactables = lazyRune(() => invoke(SeelenCommand.GetUserAppWindows));
-subscribe(SeelenEvent.UserAppWindowsChanged, interactables.setByPayload);
+export const interactables = userAppWindowsRune();
 
 export const monitors = lazyRune(() => invoke(SeelenCommand.SystemGetMonitors));
 subscribe(SeelenEvent.SystemMonitorsChanged, monitors.setByPayload);
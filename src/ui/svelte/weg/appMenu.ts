@@ -1,19 +1,28 @@
 import { invoke, SeelenCommand, Widget } from "@seelen-ui/lib";
-import type { ContextMenu, ContextMenuItem, UserAppWindow, WidgetId } from "@seelen-ui/lib/types";
+import type {
+  ContextMenu,
+  ContextMenuItem,
+  JumpList,
+  Relaunch,
+  UserAppWindow,
+  WidgetId,
+} from "@seelen-ui/lib/types";
 import type { AppOrFileWegItem } from "./types.ts";
 import { dockStateActions } from "./state/items.svelte.ts";
 import { fullSettings } from "./state/settings.svelte.ts";
+import { currentMonitorId } from "./state/getters.svelte.ts";
 import { iconPackManager } from "libs/ui/svelte/components/Icon/index.ts";
 import { prefersDarkColorScheme } from "libs/ui/svelte/runes/DarkMode.svelte.ts";
 
 const identifier = crypto.randomUUID();
+const jumpListIdentifier = crypto.randomUUID();
 const onAppMenuClick = "weg::app_menu_click";
 
 let pendingAppItem: AppOrFileWegItem | null = null;
 let pendingAppWindows: UserAppWindow[] = [];
 
 Widget.self.webview.listen(onAppMenuClick, ({ payload }) => {
-  const { key } = payload as { key: string };
+  const { key, value } = payload as { key: string; value?: unknown };
   const item = pendingAppItem;
   const windows = pendingAppWindows;
   if (!item) return;
@@ -32,8 +41,16 @@ Widget.self.webview.listen(onAppMenuClick, ({ payload }) => {
     invoke(SeelenCommand.SelectFileOnExplorer, { path: item.path });
   } else if (key === "run_as") {
     launchItem(item, true);
+  } else if (key === "jump_item" && item.umid) {
+    invoke(SeelenCommand.ActivateJumpItem, { umid: item.umid, path: value as string });
   } else if (key === "copy_hwnd") {
     navigator.clipboard.writeText(JSON.stringify(windows.map((w) => w.hwnd.toString(16))));
+  } else if (key === "move_here") {
+    windows
+      .filter((w) => w.monitor !== currentMonitorId)
+      .forEach((w) =>
+        invoke(SeelenCommand.SendWindowToMonitor, { hwnd: w.hwnd, monitorId: currentMonitorId })
+      );
   } else if (key === "close") {
     windows.forEach((w) => invoke(SeelenCommand.WegCloseApp, { hwnd: w.hwnd }));
   } else if (key === "kill") {
@@ -49,15 +66,54 @@ Widget.self.webview.listen(onAppMenuClick, ({ payload }) => {
   }
 });
 
+/** Jump lists are tracked by the shell per app user model id, apps without it have none. */
+export async function getJumpList(item: AppOrFileWegItem): Promise<JumpList | null> {
+  if (!item.umid) return null;
+  try {
+    return await invoke(SeelenCommand.GetJumpList, { umid: item.umid });
+  } catch {
+    return null;
+  }
+}
+
+function getJumpListItems(t: (key: string) => string, jumpList: JumpList): ContextMenuItem[] {
+  const seen = new Set<string>();
+  const entries = [...jumpList.recent, ...jumpList.frequent].filter((entry) => {
+    if (seen.has(entry.path)) return false;
+    seen.add(entry.path);
+    return true;
+  });
+  if (!entries.length) return [];
+
+  return [
+    {
+      type: "Submenu",
+      identifier: jumpListIdentifier,
+      icon: "MdHistory",
+      label: t("app_menu.recent"),
+      items: entries.map((entry) => ({
+        type: "Item",
+        key: "jump_item",
+        value: entry.path,
+        icon: "IoDocumentOutline",
+        label: entry.label,
+        callbackEvent: onAppMenuClick,
+      })),
+    },
+    { type: "Separator" },
+  ];
+}
+
 export function getUserApplicationContextMenu(
   t: (key: string) => string,
   item: AppOrFileWegItem,
   windows: UserAppWindow[],
+  jumpList: JumpList | null = null,
 ): ContextMenu {
   pendingAppItem = item;
   pendingAppWindows = windows;
 
-  const items: ContextMenuItem[] = [];
+  const items: ContextMenuItem[] = jumpList ? getJumpListItems(t, jumpList) : [];
 
   if (!item.preventPinning) {
     if (item.pinned) {
@@ -127,6 +183,16 @@ export function getUserApplicationContextMenu(
       });
     }
 
+    if (windows.some((w) => w.monitor !== currentMonitorId)) {
+      items.push({
+        type: "Item",
+        key: "move_here",
+        icon: "MdOutlineMonitor",
+        label: t("app_menu.move_to_this_monitor"),
+        callbackEvent: onAppMenuClick,
+      });
+    }
+
     items.push({
       type: "Item",
       key: "close",
@@ -152,12 +218,24 @@ export function getUserApplicationContextMenu(
   return { identifier, items };
 }
 
+export function getLaunchOverride(item: AppOrFileWegItem): Relaunch | undefined {
+  const overrides = fullSettings.value.byWidget["@seelen/weg"].launchOverrides;
+  const path = item.path.toLowerCase();
+  for (const [key, relaunch] of Object.entries(overrides)) {
+    if ((item.umid && key === item.umid) || key.toLowerCase() === path) {
+      return relaunch;
+    }
+  }
+  return undefined;
+}
+
 export function launchItem(item: AppOrFileWegItem, elevated: boolean) {
-  if (item.relaunch) {
+  const relaunch = getLaunchOverride(item) ?? item.relaunch;
+  if (relaunch) {
     return invoke(SeelenCommand.Run, {
-      program: item.relaunch.command,
-      args: item.relaunch.args,
-      workingDir: item.relaunch.workingDir,
+      program: relaunch.command,
+      args: relaunch.args,
+      workingDir: relaunch.workingDir,
       elevated,
     });
   }
@@ -26,6 +26,9 @@ async function handleBarMenuClick({ key, checked, value }: ContextMenuCallbackPa
     case "add-separator":
       dockStateActions.addSeparatorNear(value as { x: number; y: number });
       break;
+    case "add-spacer":
+      dockStateActions.addSpacerNear(value as { x: number; y: number });
+      break;
     case "toggle-media-module":
       if (checked) {
         dockStateActions.addMediaModule();
@@ -135,6 +138,14 @@ export function getSeelenWegMenu(
         value: cursor,
         callbackEvent: MENU_EVENT,
       },
+      {
+        type: "Item",
+        key: "add-spacer",
+        icon: "LuSpace",
+        label: t("taskbar_menu.add_spacer"),
+        value: cursor,
+        callbackEvent: MENU_EVENT,
+      },
       { type: "Separator" },
       {
         type: "Item",
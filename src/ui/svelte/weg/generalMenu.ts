@@ -1,13 +1,18 @@
 import { invoke, SeelenCommand, Widget } from "@seelen-ui/lib";
 import type { ContextMenu, ContextMenuCallbackPayload, ContextMenuItem, WidgetId } from "@seelen-ui/lib/types";
-import type { SwItem } from "./types.ts";
+import type { AppOrFileWegItem, SwItem } from "./types.ts";
 import { dockStateActions, HARDCODED_SEPARATOR_LEFT, HARDCODED_SEPARATOR_RIGHT } from "./state/items.svelte.ts";
+import { getWindowsForItem, interactables } from "./state/windows.svelte.ts";
+import { launchItem } from "./appMenu.ts";
 import { iconPackManager } from "libs/ui/svelte/components/Icon/index.ts";
+import { prefersDarkColorScheme } from "libs/ui/svelte/runes/DarkMode.svelte.ts";
 
 const identifier = crypto.randomUUID();
 const onItemMenuClick = "weg::item_menu_click";
+const onOverflowMenuClick = "weg::overflow_menu_click";
 
 let pendingItem: SwItem | null = null;
+let pendingOverflowItems: AppOrFileWegItem[] = [];
 
 Widget.self.webview.listen<ContextMenuCallbackPayload>(onItemMenuClick, ({ payload }) => {
   const { key } = payload;
@@ -30,6 +35,38 @@ Widget.self.webview.listen<ContextMenuCallbackPayload>(onItemMenuClick, ({ paylo
   }
 });
 
+Widget.self.webview.listen<ContextMenuCallbackPayload>(onOverflowMenuClick, ({ payload }) => {
+  const item = pendingOverflowItems.find((i) => i.id === payload.value);
+  if (!item) return;
+
+  const win = getWindowsForItem(item, interactables.value)[0];
+  if (win) {
+    invoke(SeelenCommand.WegToggleWindowState, { hwnd: win.hwnd, wasFocused: false });
+  } else {
+    launchItem(item, false);
+  }
+});
+
+/** Lists the apps that do not fit on the dock, clicking one focuses or launches it. */
+export function getOverflowMenu(items: AppOrFileWegItem[]): ContextMenu {
+  pendingOverflowItems = items;
+  return {
+    identifier,
+    items: items.map((item) => {
+      const found = iconPackManager.value.getIcon({ path: item.path, umid: item.umid });
+      const iconSrc = (prefersDarkColorScheme.value ? found?.dark : found?.light) || found?.base;
+      return {
+        type: "Item",
+        key: "overflow_item",
+        value: item.id,
+        icon: iconSrc ?? "IoOpenOutline",
+        label: item.displayName,
+        callbackEvent: onOverflowMenuClick,
+      };
+    }),
+  };
+}
+
 export function getEmptyTrashBinEntry(t: (key: string) => string): ContextMenuItem {
   return {
     type: "Item",
@@ -89,5 +126,19 @@ export function getMenuForItem(t: (key: string) => string, item: SwItem): Contex
     return { identifier, items };
   }
 
+  if (item.type === "Spacer") {
+    const items: ContextMenuItem[] = [
+      {
+        type: "Item",
+        key: "remove",
+        icon: "IoRemove",
+        label: t("context_menu.remove_spacer"),
+        callbackEvent: onItemMenuClick,
+      },
+    ];
+
+    return { identifier, items };
+  }
+
   return { identifier, items: [] };
 }
@@ -3,6 +3,8 @@ import { mount } from "svelte";
 import App from "./App.svelte";
 import { SeelenCommand, Widget } from "@seelen-ui/lib";
 import { invoke } from "@tauri-apps/api/core";
+import { dockState } from "./state/items.svelte.ts";
+import { getLaunchOverride } from "./appMenu.ts";
 
 import "./styles/variables.css";
 import "@seelen-ui/lib/styles/reset.css";
@@ -14,8 +16,29 @@ mount(App, {
   target: getRootContainer(),
 });
 
+// returns the executable of the app item under the given position, if any
+function getExecutableAt(x: number, y: number): string | null {
+  const el = document
+    .elementFromPoint(x / window.devicePixelRatio, y / window.devicePixelRatio)
+    ?.closest("[data-item-id]");
+  const item = dockState.items.find((i) => i.id === el?.getAttribute("data-item-id"));
+  if (item?.type !== "AppOrFile") {
+    return null;
+  }
+  // same resolution as launchItem, so files are opened with the program the user launches
+  const program = (getLaunchOverride(item) ?? item.relaunch)?.command || item.path;
+  return program.toLowerCase().endsWith(".exe") ? program : null;
+}
+
 Widget.self.window.onDragDropEvent(async (e) => {
   if (e.payload.type === "drop") {
+    // dropped over an app, open the files with it
+    const program = getExecutableAt(e.payload.position.x, e.payload.position.y);
+    if (program) {
+      await invoke(SeelenCommand.WegOpenFilesWith, { program, paths: e.payload.paths });
+      return;
+    }
+
     for (const path of e.payload.paths) {
       await invoke(SeelenCommand.WegPinItem, { path });
     }
@@ -1,6 +1,8 @@
-import { HideMode } from "@seelen-ui/lib/types";
-import { virtualDesktops } from "./getters.svelte.ts";
-import { settingsState } from "./settings.svelte.ts";
+import { invoke, SeelenCommand } from "@seelen-ui/lib";
+import { HideMode, type Rect, SeelenWegSide, type WegInstanceState } from "@seelen-ui/lib/types";
+import { debounce } from "lodash";
+import { currentMonitorId, virtualDesktops } from "./getters.svelte.ts";
+import { getDockLines, settingsState, widgetRect } from "./settings.svelte.ts";
 import { systemState } from "./system.svelte.ts";
 import { windowsState } from "./windows.svelte.ts";
 import { isThisWebviewFocused, isTouchPrimary } from "libs/ui/svelte/utils";
@@ -32,8 +34,8 @@ $effect.root(() => {
       return;
     }
 
-    const { delayToHide, delayToShow, hideMode, position } = settingsState;
-    const isMouseOverEdge = systemState.mouseAtEdge === position;
+    const { delayToHide, delayToShow, hideMode, position, revealZoneSize } = settingsState;
+    const isMouseOverEdge = systemState.isMouseAtEdge(position, revealZoneSize);
 
     let hidden = false;
     let flush = false;
@@ -54,6 +56,20 @@ $effect.root(() => {
           !isMouseOverEdge;
         flush = isTouchPrimary.value;
         break;
+      case HideMode.OnFullscreen:
+        hidden = !isTouchPrimary.value &&
+          windowsState.isFullscreenFocused &&
+          !isThisWebviewFocused.value &&
+          !isMouseOverEdge;
+        flush = isTouchPrimary.value;
+        break;
+      case HideMode.WhenMonitorUnfocused:
+        hidden = !isTouchPrimary.value &&
+          !windowsState.isMonitorFocused &&
+          !isThisWebviewFocused.value &&
+          !isMouseOverEdge;
+        flush = isTouchPrimary.value;
+        break;
     }
 
     if (_isDraggingItem) {
@@ -83,3 +99,52 @@ $effect.root(() => {
     };
   });
 });
+
+/** The 1px strip of the dock rect touching the monitor edge, reserved while the dock is hidden. */
+function edgeStrip(rect: Rect, side: SeelenWegSide): Rect {
+  switch (side) {
+    case SeelenWegSide.Top:
+      return { ...rect, bottom: rect.top + 1 };
+    case SeelenWegSide.Left:
+      return { ...rect, right: rect.left + 1 };
+    case SeelenWegSide.Right:
+      return { ...rect, left: rect.right - 1 };
+    case SeelenWegSide.Bottom:
+      return { ...rect, top: rect.bottom - 1 };
+  }
+  return rect;
+}
+
+// when auto hiding and reserving space, the revealed dock reserves its whole rect so maximized
+// windows are not covered by it. On overlap mode is excluded, as the reserved space would keep
+// the windows from overlapping the dock again.
+$effect.root(() => {
+  $effect(() => {
+    const { isReady, reservesSpace, hideMode, position } = settingsState;
+    if (!isReady || !reservesSpace || hideMode !== HideMode.Always || isTouchPrimary.value) {
+      return;
+    }
+    const { hitboxRect } = widgetRect.value;
+    invoke(SeelenCommand.RegisterAppBar, {
+      rect: _hiddenByAutohide ? edgeStrip(hitboxRect, position) : hitboxRect,
+      edge: position as any,
+    });
+  });
+});
+
+// other components and external tools can query where the dock is via `weg_get_rect`
+const reportInstanceState = debounce((state: WegInstanceState) => {
+  invoke(SeelenCommand.WegReportInstanceState, { state });
+}, 100);
+
+$effect.root(() => {
+  $effect(() => {
+    reportInstanceState({
+      monitorId: currentMonitorId,
+      rect: widgetRect.value.hitboxRect,
+      lines: getDockLines(),
+      isHidden: _hiddenByAutohide || systemState.hiddenByUser,
+      isOverlapped: windowsState.isDockOverlapped,
+    });
+  });
+});
@@ -2,7 +2,7 @@ import { invoke, SeelenCommand, SeelenEvent, subscribe } from "@seelen-ui/lib";
 import type { PluginId, WegItem, WegItems } from "@seelen-ui/lib/types";
 import { debounce } from "lodash";
 import { emit, listen } from "@tauri-apps/api/event";
-import type { AppOrFileWegItem, SeparatorWegItem } from "../types.ts";
+import type { AppOrFileWegItem, SeparatorWegItem, SpacerWegItem } from "../types.ts";
 import { getWindowsForItem, interactables } from "./windows.svelte.ts";
 import { plugins, wegItems } from "./getters.svelte.ts";
 import { isHorizontalDock } from "./settings.svelte.ts";
@@ -75,6 +75,16 @@ subscribe(SeelenEvent.WegAddItem, (e) => {
   _dockState = { ..._dockState, items };
 });
 
+subscribe(SeelenEvent.WegRemovePinnedItem, (e) => {
+  const path = e.payload.toLowerCase();
+  _dockState = {
+    ..._dockState,
+    items: _dockState.items.map((item) =>
+      item.type === "AppOrFile" && item.path.toLowerCase() === path ? { ...item, pinned: false } : item
+    ),
+  };
+});
+
 subscribe(SeelenEvent.PluginEnabled, (e) => {
   dockStateActions.addPlugin(e.payload);
 });
@@ -184,43 +194,50 @@ export const dockStateActions = {
   },
   /** Inserts a new separator next to whichever rendered item is closest to the given cursor position. */
   addSeparatorNear(cursor: { x: number; y: number }) {
-    const newSeparator: SeparatorWegItem = { id: crypto.randomUUID(), type: "Separator" };
-    const items = [..._dockState.items];
+    insertNear({ id: crypto.randomUUID(), type: "Separator" }, cursor);
+  },
+  /** Inserts a new empty slot next to whichever rendered item is closest to the given cursor position. */
+  addSpacerNear(cursor: { x: number; y: number }) {
+    insertNear({ id: crypto.randomUUID(), type: "Spacer" }, cursor);
+  },
+};
 
-    const containers = Array.from(
-      document.querySelectorAll<HTMLElement>(".weg-item-drag-container"),
-    );
+function insertNear(newItem: SeparatorWegItem | SpacerWegItem, cursor: { x: number; y: number }) {
+  const items = [..._dockState.items];
 
-    let insertIdx = items.findIndex((i) => i.id === HARDCODED_SEPARATOR_RIGHT.id);
-
-    if (containers.length > 0) {
-      const horizontal = isHorizontalDock();
-      let nearestId: string | undefined;
-      let nearestDist = Infinity;
-      let insertAfter = false;
-
-      for (const el of containers) {
-        const rect = el.getBoundingClientRect();
-        const cx = rect.left + rect.width / 2;
-        const cy = rect.top + rect.height / 2;
-        const dist = Math.hypot(cursor.x - cx, cursor.y - cy);
-        if (dist < nearestDist) {
-          nearestDist = dist;
-          nearestId = el.dataset.itemId;
-          insertAfter = horizontal ? cursor.x > cx : cursor.y > cy;
-        }
+  const containers = Array.from(
+    document.querySelectorAll<HTMLElement>(".weg-item-drag-container"),
+  );
+
+  let insertIdx = items.findIndex((i) => i.id === HARDCODED_SEPARATOR_RIGHT.id);
+
+  if (containers.length > 0) {
+    const horizontal = isHorizontalDock();
+    let nearestId: string | undefined;
+    let nearestDist = Infinity;
+    let insertAfter = false;
+
+    for (const el of containers) {
+      const rect = el.getBoundingClientRect();
+      const cx = rect.left + rect.width / 2;
+      const cy = rect.top + rect.height / 2;
+      const dist = Math.hypot(cursor.x - cx, cursor.y - cy);
+      if (dist < nearestDist) {
+        nearestDist = dist;
+        nearestId = el.dataset.itemId;
+        insertAfter = horizontal ? cursor.x > cx : cursor.y > cy;
       }
+    }
 
-      const nearestIdx = items.findIndex((i) => i.id === nearestId);
-      if (nearestIdx !== -1) {
-        insertIdx = insertAfter ? nearestIdx + 1 : nearestIdx;
-      }
+    const nearestIdx = items.findIndex((i) => i.id === nearestId);
+    if (nearestIdx !== -1) {
+      insertIdx = insertAfter ? nearestIdx + 1 : nearestIdx;
     }
+  }
 
-    items.splice(insertIdx, 0, newSeparator);
-    _dockState = { ..._dockState, items };
-  },
-};
+  items.splice(insertIdx, 0, newItem);
+  _dockState = { ..._dockState, items };
+}
 
 $effect.root(() => {
   $effect(() => {
@@ -274,7 +291,9 @@ $effect.root(() => {
         displayName: w.appName,
         umid: w.umid ?? null,
         path: w.process.path?.toString() ?? "",
-        pinned: false,
+        // apps flagged on their config are pinned on first appearance, so they keep
+        // their place on the dock after closing, like a manually pinned app
+        pinned: w.pinnedByConfig && !w.preventPinning,
         preventPinning: w.preventPinning,
         relaunch: w.relaunch ?? null,
       });
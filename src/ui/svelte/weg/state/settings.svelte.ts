@@ -1,14 +1,44 @@
 import { invoke, RuntimeStyleSheet, SeelenCommand, Widget } from "@seelen-ui/lib";
-import { Alignment, FancyToolbarSide, HideMode, SeelenWegSide } from "@seelen-ui/lib/types";
+import {
+  Alignment,
+  FancyToolbarSide,
+  HideMode,
+  type Rect,
+  SeelenWegSide,
+  type SeelenWegSettings,
+  type WegAlignment,
+  WegDockOrder,
+  type WegOverlapTrigger,
+} from "@seelen-ui/lib/types";
 import { isTouchPrimary } from "libs/ui/svelte/utils";
 import { locale } from "../i18n/index.ts";
 import { declareDocumentAsLayeredHitbox } from "libs/ui/react/utils/layered.ts";
 import { systemState } from "./system.svelte.ts";
-import { settings as _settings } from "./getters.svelte.ts";
+import {
+  getDockItemCrossSize,
+  getDockLogicalSize,
+  getDockRects,
+  getDockThickness,
+} from "libs/ui/svelte/utils/wegGeometry.ts";
+import { currentMonitorId, settings as _settings } from "./getters.svelte.ts";
 import { dateState } from "libs/ui/svelte/runes/date.svelte.ts";
 
 let isWidgetReady = $state(false);
-const settings = $derived(_settings.value.byWidget["@seelen/weg"]);
+// amount of rows (or columns) of items, set by the dock on multi-line layouts
+let dockLines = $state(1);
+
+export function setDockLines(lines: number): void {
+  dockLines = Math.max(1, lines);
+}
+
+export function getDockLines(): number {
+  return dockLines;
+}
+// the monitor patch overrides the global settings (eg: position or hide mode per monitor)
+const settings = $derived({
+  ..._settings.value.byWidget["@seelen/weg"],
+  ...((_settings.value.monitorsV3[currentMonitorId] as any)?.byWidget?.["@seelen/weg"] || {}),
+} as SeelenWegSettings);
 
 $effect.root(() => {
   $effect(() => {
@@ -63,13 +93,55 @@ class SettingsState {
     return settings;
   }
 
+  get alignment(): WegAlignment {
+    return settings.alignment;
+  }
   get position(): SeelenWegSide {
     return settings.position;
   }
 
+  get alwaysOnTop(): boolean {
+    return settings.alwaysOnTop;
+  }
+
   get hideMode(): HideMode {
     return settings.hideMode;
   }
+  get revealZoneSize(): number {
+    return settings.revealZoneSize;
+  }
+
+  /** Whether the dock should reserve its space on the work area. */
+  get reservesSpace(): boolean {
+    return settings.reserveSpace && !settings.coexistWithTaskbar && !systemState.hiddenByUser;
+  }
+
+  get isMruOrder(): boolean {
+    return settings.dockOrder === WegDockOrder.MostRecentlyUsed;
+  }
+
+  get classBlacklist(): string[] {
+    return settings.classBlacklist.map((class_) => class_.toLowerCase());
+  }
+  get titleBlacklist(): string[] {
+    return settings.titleBlacklist.map((title) => title.toLowerCase());
+  }
+  get onlyCurrentDesktop(): boolean {
+    return settings.onlyCurrentDesktop;
+  }
+  get ignoreProcessTree(): string[] {
+    return settings.ignoreProcessTree.map((exe) => exe.toLowerCase());
+  }
+
+  get overlapBlacklistExe(): string[] {
+    return settings.overlapBlacklistExe.map((exe) => exe.toLowerCase());
+  }
+  get overlapBlacklistTitle(): string[] {
+    return settings.overlapBlacklistTitle.map((title) => title.toLowerCase());
+  }
+  get overlapTrigger(): WegOverlapTrigger {
+    return settings.overlapTrigger;
+  }
 
   get delayToHide(): number {
     return settings.delayToHide;
@@ -87,64 +159,72 @@ export function isHorizontalDock(): boolean {
   return pos === SeelenWegSide.Top || pos === SeelenWegSide.Bottom;
 }
 
+// the native taskbar is kept visible on this monitor, so the dock is placed next to it
+const keepsNativeTaskbar = $derived(
+  settings.coexistWithTaskbar || settings.keepNativeTaskbarOn.includes(currentMonitorId),
+);
+let nativeTaskbarRect = $state<Rect | null>(null);
+
+function excludeNativeTaskbar(area: Rect): Rect {
+  const bar = nativeTaskbarRect;
+  if (!keepsNativeTaskbar || !bar) {
+    return area;
+  }
+
+  const monitor = systemState.currentMonitor.rect;
+  const isHorizontalBar = bar.right - bar.left >= bar.bottom - bar.top;
+  if (isHorizontalBar) {
+    const atTop = bar.top - monitor.top < monitor.bottom - bar.bottom;
+    return atTop
+      ? { ...area, top: Math.max(area.top, bar.bottom) }
+      : { ...area, bottom: Math.min(area.bottom, bar.top) };
+  }
+  const atLeft = bar.left - monitor.left < monitor.right - bar.right;
+  return atLeft
+    ? { ...area, left: Math.max(area.left, bar.right) }
+    : { ...area, right: Math.min(area.right, bar.left) };
+}
+
 const workArea = {
   get value() {
-    const workArea = systemState.currentMonitor.rect;
-    const tbConfig = _settings.value.byWidget["@seelen/fancy-toolbar"];
-    const tbMonitorConfig = (_settings.value.monitorsV3[systemState.currentMonitor.id] as any)
-      ?.byWidget?.["@seelen/fancy-toolbar"] || { enabled: true };
+    return excludeNativeTaskbar(areaWithoutToolbar());
+  },
+};
 
-    if (!tbConfig?.enabled || !tbMonitorConfig?.enabled) {
-      return workArea;
-    }
+function areaWithoutToolbar(): Rect {
+  const workArea = systemState.currentMonitor.rect;
+  const tbConfig = _settings.value.byWidget["@seelen/fancy-toolbar"];
+  const tbMonitorConfig = (_settings.value.monitorsV3[systemState.currentMonitor.id] as any)
+    ?.byWidget?.["@seelen/fancy-toolbar"] || { enabled: true };
 
-    const tbSize = Math.round(
-      (tbConfig.itemSize + tbConfig.padding * 2 + tbConfig.margin * 2) *
-        systemState.currentMonitor.scaleFactor,
-    );
+  if (!tbConfig?.enabled || !tbMonitorConfig?.enabled) {
+    return workArea;
+  }
 
-    switch (tbConfig.position) {
-      case FancyToolbarSide.Top:
-        return { ...workArea, top: workArea.top + tbSize };
-      case FancyToolbarSide.Bottom:
-        return { ...workArea, bottom: workArea.bottom - tbSize };
-    }
+  const tbSize = Math.round(
+    (tbConfig.itemSize + tbConfig.padding * 2 + tbConfig.margin * 2) *
+      systemState.scaleFactor,
+  );
 
-    return workArea;
-  },
-};
+  switch (tbConfig.position) {
+    case FancyToolbarSide.Top:
+      return { ...workArea, top: workArea.top + tbSize };
+    case FancyToolbarSide.Bottom:
+      return { ...workArea, bottom: workArea.bottom - tbSize };
+  }
+
+  return workArea;
+}
 
 export const widgetRect = {
   get value() {
-    const wa = { ...workArea.value };
-    const hitboxRect = { ...workArea.value };
-    const webviewRect = { ...workArea.value };
-
-    const size = Math.round(
-      (settings.size + settings.padding * 2 + settings.margin * 2) *
-        systemState.currentMonitor.scaleFactor,
+    return getDockRects(
+      workArea.value,
+      settings.position,
+      getDockLogicalSize(settings, dockLines),
+      systemState.scaleFactor,
+      isTouchPrimary.value,
     );
-
-    switch (settings.position) {
-      case SeelenWegSide.Left:
-        hitboxRect.right = hitboxRect.left + size;
-        webviewRect.right = isTouchPrimary.value ? hitboxRect.right : wa.right - Math.round((wa.right - wa.left) / 2);
-        break;
-      case SeelenWegSide.Right:
-        hitboxRect.left = hitboxRect.right - size;
-        webviewRect.left = isTouchPrimary.value ? hitboxRect.left : wa.left + Math.round((wa.right - wa.left) / 2);
-        break;
-      case SeelenWegSide.Top:
-        hitboxRect.bottom = hitboxRect.top + size;
-        webviewRect.bottom = isTouchPrimary.value ? hitboxRect.bottom : wa.top + Math.round((wa.bottom - wa.top) / 2);
-        break;
-      case SeelenWegSide.Bottom:
-        hitboxRect.top = hitboxRect.bottom - size;
-        webviewRect.top = isTouchPrimary.value ? hitboxRect.top : wa.bottom - Math.round((wa.bottom - wa.top) / 2);
-        break;
-    }
-
-    return { hitboxRect, webviewRect };
   },
 };
 
@@ -153,6 +233,7 @@ async function updateWidgetPosition() {
   const isTouch = isTouchPrimary.value;
   const hideMode = settings.hideMode;
   const position = settings.position;
+  const reserveSpace = settingsState.reservesSpace;
   const isReady = settingsState.isReady;
 
   await Widget.self.setPosition(webviewRect);
@@ -161,12 +242,14 @@ async function updateWidgetPosition() {
     return;
   }
 
-  if (hideMode === HideMode.Never || isTouch) {
+  // fullscreen windows are placed over the app bar so the work area can be reserved
+  if (reserveSpace && (hideMode === HideMode.Never || hideMode === HideMode.OnFullscreen || isTouch)) {
     await invoke(SeelenCommand.RegisterAppBar, {
       rect: hitboxRect,
       edge: position as any,
     });
-  } else {
+  } else if (!reserveSpace || hideMode !== HideMode.Always) {
+    // while auto hiding, the reserved rect follows the hidden state, see hidden.svelte.ts
     await invoke(SeelenCommand.UnregisterAppBar);
   }
 }
@@ -180,13 +263,18 @@ Widget.self.window.onMoved(({ payload }) => {
 
 $effect.root(() => {
   $effect(() => {
-    const { size, padding, margin, spaceBetweenItems, zoomSize } = settings;
+    const { size, padding, margin, spaceBetweenItems, zoomSize, animationDuration, maxItemsPerLine } = settings;
     const sheet = new RuntimeStyleSheet("@config/weg");
     sheet.addVariable("--config-margin", `${margin}px`);
     sheet.addVariable("--config-padding", `${padding}px`);
+    sheet.addVariable("--config-thickness", `${getDockThickness(settings, dockLines)}px`);
     sheet.addVariable("--config-item-size", `${size}px`);
+    sheet.addVariable("--config-item-cross-size", `${getDockItemCrossSize(settings)}px`);
     sheet.addVariable("--config-item-zoom-size", `${zoomSize}px`);
     sheet.addVariable("--config-space-between-items", `${spaceBetweenItems}px`);
+    sheet.addVariable("--config-animation-duration", `${animationDuration}ms`);
+    sheet.addVariable("--config-max-items-per-line", `${maxItemsPerLine}`);
+    sheet.addVariable("--config-dock-lines", `${dockLines}`);
     sheet.applyToDocument();
   });
 
@@ -194,6 +282,20 @@ $effect.root(() => {
     updateWidgetPosition();
   });
 
+  $effect(() => {
+    // also re-read when the monitor changes
+    const _monitorRect = systemState.currentMonitor.rect;
+    if (!keepsNativeTaskbar) {
+      nativeTaskbarRect = null;
+      return;
+    }
+    // the service restores the native taskbar asynchronously
+    const timeout = setTimeout(async () => {
+      nativeTaskbarRect = await invoke(SeelenCommand.WegGetNativeTaskbarRect, { monitorId: currentMonitorId });
+    }, 500);
+    return () => clearTimeout(timeout);
+  });
+
   $effect(() => {
     if (isTouchPrimary.value) return;
 
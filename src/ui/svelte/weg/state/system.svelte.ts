@@ -1,6 +1,32 @@
-import { SeelenWegSide } from "@seelen-ui/lib/types";
+import { invoke, SeelenCommand, SeelenEvent, subscribe, Widget } from "@seelen-ui/lib";
+import { SeelenWegSide, type WegMonitorInfo } from "@seelen-ui/lib/types";
 import { currentMonitorId, monitors, mousePos } from "./getters.svelte.ts";
 
+// toggled from the cli, without monitor all docks are toggled
+let _hiddenByUser = $state(false);
+subscribe(SeelenEvent.WegToggleVisibility, ({ payload }) => {
+  if (payload && payload !== currentMonitorId) {
+    return;
+  }
+  _hiddenByUser = !_hiddenByUser;
+  if (_hiddenByUser) {
+    Widget.self.hide();
+  } else {
+    Widget.self.show();
+  }
+});
+
+// dpi and work area of the monitor of this dock, as seen by the system
+let _monitorInfo = $state<WegMonitorInfo | null>(null);
+invoke(SeelenCommand.WegGetMonitorInfo).then((info) => {
+  _monitorInfo = info;
+});
+subscribe(SeelenEvent.WegMonitorInfoChanged, ({ payload }) => {
+  if (payload.monitorId === currentMonitorId) {
+    _monitorInfo = payload;
+  }
+});
+
 const _currentMonitor = $derived.by(() => {
   const monitor = monitors.value.find((m) => m.id === currentMonitorId);
   if (!monitor) {
@@ -9,30 +35,54 @@ const _currentMonitor = $derived.by(() => {
   return monitor;
 });
 
-const _mouseAtEdge = $derived.by((): SeelenWegSide | null => {
+// the reported monitor info is preferred as it is updated as soon as the dpi changes
+const _scaleFactor = $derived(_monitorInfo?.scaleFactor ?? _currentMonitor.scaleFactor);
+
+/**
+ * Whether the cursor is inside the reveal zone: the strip of `zoneSize` logical pixels
+ * along the given edge of the current monitor. Only the cursor position is tracked,
+ * so the zone never steals clicks from the apps below it.
+ */
+function isMouseAtEdge(side: SeelenWegSide, zoneSize: number): boolean {
   const box = _currentMonitor.rect;
   const x = mousePos.value.x;
   const y = mousePos.value.y;
 
-  if (x < box.left || x > box.right || y < box.top || y > box.bottom) {
-    return null;
+  // right and bottom are exclusive, those pixels belong to the neighbor monitors
+  if (x < box.left || x >= box.right || y < box.top || y >= box.bottom) {
+    return false;
   }
 
-  if (y === box.top) return SeelenWegSide.Top;
-  if (x === box.left) return SeelenWegSide.Left;
-  if (y === box.bottom - 1) return SeelenWegSide.Bottom;
-  if (x === box.right - 1) return SeelenWegSide.Right;
-
-  return null;
-});
+  const zone = Math.max(1, Math.round(zoneSize * _scaleFactor));
+  switch (side) {
+    case SeelenWegSide.Top:
+      return y < box.top + zone;
+    case SeelenWegSide.Left:
+      return x < box.left + zone;
+    case SeelenWegSide.Bottom:
+      return y >= box.bottom - zone;
+    case SeelenWegSide.Right:
+      return x >= box.right - zone;
+  }
+  return false;
+}
 
 class SystemState {
   get currentMonitor() {
     return _currentMonitor;
   }
 
-  get mouseAtEdge(): SeelenWegSide | null {
-    return _mouseAtEdge;
+  isMouseAtEdge(side: SeelenWegSide, zoneSize: number): boolean {
+    return isMouseAtEdge(side, zoneSize);
+  }
+
+  /** dpi ratio of the monitor of this dock */
+  get scaleFactor(): number {
+    return _scaleFactor;
+  }
+
+  get hiddenByUser(): boolean {
+    return _hiddenByUser;
   }
 }
 
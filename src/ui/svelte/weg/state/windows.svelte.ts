@@ -1,11 +1,24 @@
 import { SeelenEvent, subscribe, Widget } from "@seelen-ui/lib";
-import { type FocusedApp, SeelenWegSide, type UserAppWindow, type UserAppWindowColors } from "@seelen-ui/lib/types";
+import {
+  type FocusedApp,
+  SeelenWegSide,
+  type UserAppWindow,
+  type UserAppWindowColors,
+  WegOverlapTrigger,
+} from "@seelen-ui/lib/types";
 import { settingsState, widgetRect } from "./settings.svelte.ts";
 import { debounce } from "lodash";
 import type { AppOrFileWegItem } from "../types.ts";
-import { focused, interactables, previews, selfWinId, widgetStatuses, windowsColors } from "./getters.svelte.ts";
+import {
+  focused,
+  interactables as allInteractables,
+  previews,
+  selfWinId,
+  widgetStatuses,
+  windowsColors,
+} from "./getters.svelte.ts";
 
-export { focused, interactables, previews, widgetStatuses, windowsColors };
+export { focused, previews, widgetStatuses, windowsColors };
 
 const widget = Widget.getCurrent();
 
@@ -23,14 +36,14 @@ subscribe(SeelenEvent.GlobalFocusChanged, (e) => {
 });
 
 const _topInteractableWindow = $derived(
-  interactables.value
+  allInteractables.value
     .toSorted((a, b) => b.lastForegroundAt - a.lastForegroundAt)
     .find((w) => w.monitor === widget.decoded.monitorId && !w.isIconic),
 );
 
 const _currentMonitorMaximizedColors = $derived.by((): UserAppWindowColors | null => {
   const monitorId = widget.decoded.monitorId;
-  const maximized = interactables.value.find(
+  const maximized = allInteractables.value.find(
     (w) => !w.isIconic && w.isZoomed && w.monitor === monitorId,
   );
   if (!maximized) return null;
@@ -40,14 +53,21 @@ const _currentMonitorMaximizedColors = $derived.by((): UserAppWindowColors | nul
 const _isDockOverlapped = $derived.by(() => {
   // If foreground is not in interactable windows, return false directly, this handled start menu or desktop focus cases.
   const foreground = focused.value;
-  if (!interactables.value.some((w) => w.hwnd === foreground.hwnd)) {
+  if (!allInteractables.value.some((w) => w.hwnd === foreground.hwnd)) {
     return false;
   }
 
   // Check if any interactable window overlaps with the hitbox
   const a = widgetRect.value.hitboxRect;
-  for (const app of interactables.value) {
+  const onlyFocused = settingsState.overlapTrigger === WegOverlapTrigger.Focused;
+  const blacklistExe = settingsState.overlapBlacklistExe;
+  const blacklistTitle = settingsState.overlapBlacklistTitle;
+  const ignoredTree = settingsState.ignoreProcessTree;
+  for (const app of allInteractables.value) {
+    if (onlyFocused && app.hwnd !== foreground.hwnd) continue;
     if (app.monitor !== widget.decoded.monitorId || app.isIconic || !app.rect) continue;
+    if (isOverlapBlacklisted(app, blacklistExe, blacklistTitle)) continue;
+    if (isOnIgnoredProcessTree(app, ignoredTree)) continue;
     const b = app.rect;
 
     if (!(a.right <= b.left || a.left >= b.right || a.bottom <= b.top || a.top >= b.bottom)) {
@@ -58,6 +78,74 @@ const _isDockOverlapped = $derived.by(() => {
   return false;
 });
 
+function isOverlapBlacklisted(app: UserAppWindow, exes: string[], titles: string[]): boolean {
+  const exe = app.process.path?.split("\\").pop()?.toLowerCase();
+  if (exe && exes.includes(exe)) {
+    return true;
+  }
+  const title = app.title.toLowerCase();
+  return titles.some((t) => !!t && title.includes(t));
+}
+
+/** Matches case-insensitively, `*` in the pattern matches any sequence of characters. */
+function matchesPattern(value: string, pattern: string): boolean {
+  if (!pattern.includes("*")) {
+    return value === pattern;
+  }
+  const escaped = pattern.split("*").map((part) => part.replace(/[.+?^${}()|[\]\\]/g, "\\$&"));
+  return new RegExp(`^${escaped.join(".*")}$`).test(value);
+}
+
+/** the window process or any of its parent processes is one of the given executables */
+function isOnIgnoredProcessTree(app: UserAppWindow, exes: string[]): boolean {
+  if (exes.length === 0) {
+    return false;
+  }
+  const exe = app.process.path?.split("\\").pop()?.toLowerCase();
+  if (exe && exes.includes(exe)) {
+    return true;
+  }
+  return app.process.ancestors.some((ancestor) => exes.includes(ancestor.toLowerCase()));
+}
+
+function isHiddenFromDock(app: UserAppWindow, classes: string[], titles: string[]): boolean {
+  const class_ = app.class.toLowerCase();
+  if (classes.some((c) => !!c && matchesPattern(class_, c))) {
+    return true;
+  }
+  const title = app.title.toLowerCase();
+  return titles.some((t) => !!t && matchesPattern(title, t));
+}
+
+/** interactable windows without the ones hidden from the dock by the user or on other workspaces */
+const _interactables = $derived.by(() => {
+  const classes = settingsState.classBlacklist;
+  const titles = settingsState.titleBlacklist;
+  const ignoredTree = settingsState.ignoreProcessTree;
+  const windows = settingsState.onlyCurrentDesktop
+    ? allInteractables.value.filter((w) => w.onCurrentDesktop)
+    : allInteractables.value;
+  if (classes.length === 0 && titles.length === 0 && ignoredTree.length === 0) {
+    return windows;
+  }
+  return windows.filter(
+    (w) => !isHiddenFromDock(w, classes, titles) && !isOnIgnoredProcessTree(w, ignoredTree),
+  );
+});
+
+export const interactables = {
+  get value() {
+    return _interactables;
+  },
+};
+
+// maximized windows are not considered fullscreen as they keep their borders (WS_THICKFRAME)
+const _isFullscreenFocused = $derived(
+  !!focused.value?.isFullscreened && focused.value.monitor === widget.decoded.monitorId,
+);
+
+const _isMonitorFocused = $derived(focused.value?.monitor === widget.decoded.monitorId);
+
 class WindowsState {
   get topInteractableWindow() {
     return _topInteractableWindow;
@@ -70,6 +158,14 @@ class WindowsState {
   get isDockOverlapped() {
     return _isDockOverlapped;
   }
+
+  get isFullscreenFocused() {
+    return _isFullscreenFocused;
+  }
+
+  get isMonitorFocused() {
+    return _isMonitorFocused;
+  }
 }
 
 export const windowsState = new WindowsState();
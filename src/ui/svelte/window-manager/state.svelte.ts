@@ -6,6 +6,8 @@ import { SeelenWegSide } from "node_modules/@seelen-ui/lib/esm/gen/types/SeelenW
 
 import { lazyRune } from "libs/ui/svelte/utils/LazyRune.svelte.ts";
 import { isTouchPrimary } from "libs/ui/svelte/utils/signals.svelte.ts";
+import { userAppWindowsRune } from "libs/ui/svelte/utils/userAppWindows.svelte.ts";
+import { getDockLogicalSize, getDockRects } from "libs/ui/svelte/utils/wegGeometry.ts";
 
 let layouts = lazyRune(() => invoke(SeelenCommand.WmGetRenderTree));
 subscribe(SeelenEvent.WMTreeChanged, layouts.setByPayload);
@@ -13,8 +15,7 @@ subscribe(SeelenEvent.WMTreeChanged, layouts.setByPayload);
 let workspaces = lazyRune(() => invoke(SeelenCommand.StateGetVirtualDesktops));
 subscribe(SeelenEvent.VirtualDesktopsChanged, workspaces.setByPayload);
 
-let interactables = lazyRune(() => invoke(SeelenCommand.GetUserAppWindows));
-subscribe(SeelenEvent.UserAppWindowsChanged, interactables.setByPayload);
+let interactables = userAppWindowsRune();
 
 let monitors = lazyRune(() => invoke(SeelenCommand.SystemGetMonitors));
 subscribe(SeelenEvent.SystemMonitorsChanged, monitors.setByPayload);
@@ -75,6 +76,17 @@ $effect.root(() => {
 
 const monitorId = Widget.getCurrent().decoded.monitorId!;
 
+// rows (or columns) of items of the dock on this monitor, multi-line docks are thicker
+let dockLines = $state(1);
+invoke(SeelenCommand.WegGetRect, { monitorId })
+  .then((state) => (dockLines = state.lines))
+  .catch(() => {});
+subscribe(SeelenEvent.WegInstanceStateChanged, ({ payload }) => {
+  if (payload.monitorId === monitorId) {
+    dockLines = payload.lines;
+  }
+});
+
 const widgetRect = $derived.by(() => {
   const monitor = monitors.value.find((m) => m.id === monitorId);
   if (!monitor) {
@@ -92,7 +104,9 @@ const widgetRect = $derived.by(() => {
   if (
     tbConfig.enabled &&
     tbMonitorConfig.enabled &&
-    (tbConfig.hideMode === HideMode.Never || isTouchPrimary.value)
+    (tbConfig.hideMode === HideMode.Never ||
+      tbConfig.hideMode === HideMode.OnFullscreen ||
+      isTouchPrimary.value)
   ) {
     const tbSize = Math.round(
       (tbConfig.itemSize + tbConfig.padding * 2 + tbConfig.margin * 2) * monitor.scaleFactor,
@@ -107,33 +121,44 @@ const widgetRect = $derived.by(() => {
     }
   }
 
-  const wegConfig = fullSettings.byWidget["@seelen/weg"];
   const wegMonitorConfig = (fullSettings.monitorsV3[monitor.id] as any)?.byWidget?.[
     "@seelen/weg"
   ] ?? {
     enabled: true,
   };
+  const wegGlobalConfig = fullSettings.byWidget["@seelen/weg"];
+  // position and hide mode can be overridden per monitor
+  const wegConfig = { ...wegGlobalConfig, ...wegMonitorConfig };
 
   if (
-    wegConfig.enabled &&
+    wegGlobalConfig.enabled &&
     wegMonitorConfig.enabled &&
-    (wegConfig.hideMode === HideMode.Never || isTouchPrimary.value)
+    wegConfig.reserveSpace &&
+    !wegConfig.coexistWithTaskbar &&
+    (wegConfig.hideMode === HideMode.Never ||
+      wegConfig.hideMode === HideMode.OnFullscreen ||
+      isTouchPrimary.value)
   ) {
-    const wegSize = Math.round(
-      (wegConfig.size + wegConfig.padding * 2 + wegConfig.margin * 2) * monitor.scaleFactor,
+    // same size as reserved by the dock itself
+    const { hitboxRect } = getDockRects(
+      rect,
+      wegConfig.position,
+      getDockLogicalSize(wegConfig, dockLines),
+      monitor.scaleFactor,
+      true,
     );
     switch (wegConfig.position) {
       case SeelenWegSide.Top:
-        rect.top += wegSize;
+        rect.top = hitboxRect.bottom;
         break;
       case SeelenWegSide.Bottom:
-        rect.bottom -= wegSize;
+        rect.bottom = hitboxRect.top;
         break;
       case SeelenWegSide.Left:
-        rect.left += wegSize;
+        rect.left = hitboxRect.right;
         break;
       case SeelenWegSide.Right:
-        rect.right -= wegSize;
+        rect.right = hitboxRect.left;
         break;
     }
   }
@@ -1,6 +1,6 @@
 import { invoke, type Rect, SeelenCommand, SeelenEvent, subscribe, Widget } from "@seelen-ui/lib";
 import type { Wallpaper } from "@seelen-ui/lib/types";
-import { lazyRune } from "libs/ui/svelte/utils";
+import { lazyRune, userAppWindowsRune } from "libs/ui/svelte/utils";
 
 let monitors = lazyRune(() => invoke(SeelenCommand.SystemGetMonitors));
 subscribe(SeelenEvent.SystemMonitorsChanged, monitors.setByPayload);
@@ -11,8 +11,7 @@ subscribe(SeelenEvent.StateWallpapersChanged, wallpapers.setByPayload);
 let workspaces = lazyRune(() => invoke(SeelenCommand.StateGetVirtualDesktops));
 subscribe(SeelenEvent.VirtualDesktopsChanged, workspaces.setByPayload);
 
-let windows = lazyRune(() => invoke(SeelenCommand.GetUserAppWindows));
-subscribe(SeelenEvent.UserAppWindowsChanged, windows.setByPayload);
+let windows = userAppWindowsRune();
 
 let previews = lazyRune(() => invoke(SeelenCommand.GetUserAppWindowsPreviews));
 subscribe(SeelenEvent.UserAppWindowsPreviewsChanged, previews.setByPayload);